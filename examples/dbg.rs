@@ -1,5 +1,5 @@
 use iterlist::IterList;
 
 fn main() {
-	let thing: IterList<[u8]> = IterList::new();
+	let _thing: IterList<[u8]> = IterList::new();
 }
@@ -0,0 +1,1390 @@
+use std::fmt::Debug;
+use std::cmp::Ordering;
+use std::iter::FusedIterator;
+use std::mem;
+use std::ops::Not;
+
+/// Stands in for a missing link, like `None` does in the boxed list.
+const NIL: u32 = u32::MAX;
+
+/// An IterList keeping all of its nodes in a single `Vec`, linked by `u32` indices instead of pointers.
+/// Removed nodes go on a free list and their slots are reused by later insertions,
+/// so a list that stays around the same size stops allocating altogether.
+///
+/// It has the same cursor based api as the boxed `IterList`, with one catch:
+/// every list owns its own arena, so splitting and joining lists moves the elements over,
+/// which is `O(n)` rather than `O(1)`.
+/// A list can hold at most `u32::MAX` elements.
+/// ```
+/// # use iterlist::arena::IterList;
+/// let mut list = IterList::from(vec![1, 2, 3]);
+/// let capacity = list.capacity();
+/// list.advance();
+///
+/// assert_eq!(list.consume_forward(), Some((2, true)));
+/// list.insert_prev(4);
+///
+/// assert_eq!(format!("{:?}", list), "[1, 4, 3]");
+/// assert_eq!(list.current(), Some(&3));
+/// assert_eq!(list.capacity(), capacity);
+/// ```
+pub struct IterList<T> {
+	nodes:   Vec<Slot<T>>,
+	free:    u32,
+	current: u32,
+	front:   u32,
+	back:    u32,
+	index:   usize,
+	len:     usize,
+}
+
+#[derive(Clone)]
+struct Node<T> {
+	next: u32,
+	prev: u32,
+	elem: T,
+}
+
+/// A slot of the arena, holding either a node or the index of the next free slot.
+#[derive(Clone)]
+enum Slot<T> {
+	Used(Node<T>),
+	Free(u32),
+}
+
+impl<T> IterList<T> {
+	/// Create a new empty list. `O(1)`.
+	/// Does not allocate any memory.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let list: IterList<u8> = IterList::new();
+	/// assert_eq!(list.len(), 0);
+	/// assert_eq!(list.capacity(), 0);
+	/// ```
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			nodes:   Vec::new(),
+			free:    NIL,
+			current: NIL,
+			front:   NIL,
+			back:    NIL,
+			index:   0,
+			len:     0,
+		}
+	}
+
+	/// Create a new empty list with room for at least `capacity` elements. `O(1)`.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let list: IterList<u8> = IterList::with_capacity(8);
+	/// assert!(list.is_empty());
+	/// assert!(list.capacity() >= 8);
+	/// ```
+	#[inline]
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self { nodes: Vec::with_capacity(capacity), ..Self::new() }
+	}
+
+	/// Get the number of elements the list can hold without allocating. `O(1)`.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// let capacity = list.capacity();
+	///
+	/// list.pop_back();
+	/// list.push_front(0);
+	/// assert_eq!(list.capacity(), capacity);
+	/// ```
+	#[inline]
+	pub fn capacity(&self) -> usize {
+		self.nodes.capacity()
+	}
+
+	/// Make room for at least `additional` more elements. `O(1)`, or `O(n)` if the arena has to grow.
+	/// Free slots count towards the room, so this doesn't allocate if enough elements have been removed.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1]);
+	/// list.reserve(10);
+	///
+	/// assert!(list.capacity() >= 11);
+	/// ```
+	#[inline]
+	pub fn reserve(&mut self, additional: usize) {
+		self.nodes.reserve(additional.saturating_sub(self.nodes.len() - self.len));
+	}
+
+	/// Insert an element after the cursor, retaining current position. `O(1)`.
+	/// If the list is empty it will be inserted at index 0.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::new();
+	/// list.insert_next(1);
+	/// list.insert_next(2);
+	/// list.insert_next(3);
+	///
+	/// assert_eq!(list.current(), Some(&1));
+	/// assert_eq!(&format!("{:?}", list), "[1, 3, 2]");
+	/// ```
+	pub fn insert_next(&mut self, elem: T) {
+		if self.len == 0 { return self.reset_to(elem); }
+
+		let current = self.current;
+		let next = self.node(current).next;
+		let new  = self.alloc(Node { next, prev: current, elem });
+
+		match next {
+			NIL  => self.back = new,
+			next => self.node_mut(next).prev = new,
+		}
+		self.node_mut(current).next = new;
+		self.len += 1;
+	}
+
+	/// Insert an element before the cursor, retaining current position. `O(1)`.
+	/// If the list is empty it will be inserted at index 0.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::new();
+	/// list.insert_prev(1);
+	/// list.insert_prev(2);
+	/// list.insert_prev(3);
+	///
+	/// assert_eq!(list.current(), Some(&1));
+	/// assert_eq!(&format!("{:?}", list), "[2, 3, 1]");
+	/// ```
+	pub fn insert_prev(&mut self, elem: T) {
+		if self.len == 0 { return self.reset_to(elem); }
+
+		let current = self.current;
+		let prev = self.node(current).prev;
+		let new  = self.alloc(Node { next: current, prev, elem });
+
+		match prev {
+			NIL  => self.front = new,
+			prev => self.node_mut(prev).next = new,
+		}
+		self.node_mut(current).prev = new;
+		self.len   += 1;
+		self.index += 1;
+	}
+
+	/// Push an element after the cursor, moving the cursor to it. `O(1)`.
+	/// If the list is empty it will be inserted at index 0.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::new();
+	/// list.push_next(1);
+	/// list.push_next(2);
+	/// list.push_next(3);
+	///
+	/// assert_eq!(list.current(), Some(&3));
+	/// assert_eq!(&format!("{:?}", list), "[1, 2, 3]");
+	/// ```
+	pub fn push_next(&mut self, elem: T) {
+		self.insert_next(elem);
+		let _ = self.advance();
+	}
+
+	/// Push an element before the cursor, moving the cursor to it. `O(1)`.
+	/// If the list is empty it will be inserted at index 0.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::new();
+	/// list.push_prev(1);
+	/// list.push_prev(2);
+	/// list.push_prev(3);
+	///
+	/// assert_eq!(list.current(), Some(&3));
+	/// assert_eq!(&format!("{:?}", list), "[3, 2, 1]");
+	/// ```
+	pub fn push_prev(&mut self, elem: T) {
+		self.insert_prev(elem);
+		let _ = self.retreat();
+	}
+
+	/// Push an element to the front of the list, leaving the cursor on its element. `O(1)`.
+	/// If the list is empty it will be inserted at index 0.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![2, 3]);
+	/// list.advance();
+	/// list.push_front(1);
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+	/// assert_eq!(list.current(), Some(&3));
+	/// assert_eq!(list.index(), 2);
+	/// ```
+	pub fn push_front(&mut self, elem: T) {
+		if self.len == 0 { return self.reset_to(elem); }
+
+		let front = self.front;
+		let new   = self.alloc(Node { next: front, prev: NIL, elem });
+
+		self.node_mut(front).prev = new;
+		self.front  = new;
+		self.len   += 1;
+		self.index += 1;
+	}
+
+	/// Push an element to the back of the list, leaving the cursor on its element. `O(1)`.
+	/// If the list is empty it will be inserted at index 0.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2]);
+	/// list.push_back(3);
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	pub fn push_back(&mut self, elem: T) {
+		if self.len == 0 { return self.reset_to(elem); }
+
+		let back = self.back;
+		let new  = self.alloc(Node { next: NIL, prev: back, elem });
+
+		self.node_mut(back).next = new;
+		self.back = new;
+		self.len += 1;
+	}
+
+	/// Remove the first element and return it. `O(1)`.
+	/// The cursor stays on its element, unless it was the one removed,
+	/// in which case it moves to the new front, just like `consume_forward`.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// list.move_to_back();
+	///
+	/// assert_eq!(list.pop_front(), Some(1));
+	/// assert_eq!(format!("{:?}", list), "[2, 3]");
+	/// assert_eq!(list.current(), Some(&3));
+	/// assert_eq!(list.index(), 1);
+	/// ```
+	pub fn pop_front(&mut self) -> Option<T> {
+		if self.len == 0 { return None; }
+		if self.current == self.front { return self.consume_forward().map(|(elem, _)| elem); }
+
+		self.index -= 1;
+		Some(self.unlink(self.front).elem)
+	}
+
+	/// Remove the last element and return it. `O(1)`.
+	/// The cursor stays on its element, unless it was the one removed,
+	/// in which case it moves to the new back, just like `consume_backward`.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	///
+	/// assert_eq!(list.pop_back(), Some(3));
+	/// assert_eq!(format!("{:?}", list), "[1, 2]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	pub fn pop_back(&mut self) -> Option<T> {
+		if self.len == 0 { return None; }
+		if self.current == self.back { return self.consume_backward().map(|(elem, _)| elem); }
+
+		Some(self.unlink(self.back).elem)
+	}
+
+	/// Move the cursor to the front of the list. `O(1)`.
+	/// Returns the number of elements skipped over.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// list.move_to_back();
+	///
+	/// assert_eq!(list.move_to_front(), 2);
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	pub fn move_to_front(&mut self) -> usize {
+		self.current = self.front;
+		mem::take(&mut self.index)
+	}
+
+	/// Move the cursor to the back of the list. `O(1)`.
+	/// Returns the number of elements skipped over.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	///
+	/// assert_eq!(list.move_to_back(), 2);
+	/// assert_eq!(list.current(), Some(&3));
+	/// ```
+	pub fn move_to_back(&mut self) -> usize {
+		if self.len == 0 { return 0; }
+
+		let skipped = self.len - 1 - self.index;
+		self.current = self.back;
+		self.index   = self.len - 1;
+		skipped
+	}
+
+	/// Check if the cursor is on the first element. `O(1)`.
+	/// Returns `false` if the list is empty.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// assert!(list.is_at_front());
+	///
+	/// list.advance();
+	/// assert!(!list.is_at_front());
+	/// ```
+	#[inline]
+	pub fn is_at_front(&self) -> bool {
+		self.len != 0 && self.current == self.front
+	}
+
+	/// Check if the cursor is on the last element. `O(1)`.
+	/// Returns `false` if the list is empty.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// assert!(!list.is_at_back());
+	///
+	/// list.move_to_back();
+	/// assert!(list.is_at_back());
+	/// ```
+	#[inline]
+	pub fn is_at_back(&self) -> bool {
+		self.len != 0 && self.current == self.back
+	}
+
+	/// Move the cursor to the specified index. `O(n)`.
+	/// Walks from whichever of the front, the back or the cursor is closest.
+	/// If the index is out of bounds the cursor will be moved to the edge,
+	/// and `false` will be returned.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// assert!(list.move_to(1));
+	/// assert_eq!(list.current(), Some(&2));
+	///
+	/// assert!(!list.move_to(5));
+	/// assert_eq!(list.current(), Some(&3));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn move_to(&mut self, index: usize) -> bool {
+		match self.node_at(index, (self.index, self.current)) {
+			Some(node) => {
+				self.current = node;
+				self.index   = index;
+				true
+			},
+			None => {
+				self.move_to_back();
+				self.is_empty() && index == 0
+			},
+		}
+	}
+
+	/// Move the cursor one step forward. `O(1)`.
+	/// Returns `false` if the cursor could not be moved.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	///
+	/// assert!(list.advance());
+	/// assert_eq!(list.current(), Some(&2));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn advance(&mut self) -> bool {
+		if self.len == 0 { return false; }
+
+		match self.node(self.current).next {
+			NIL  => false,
+			next => { self.current = next; self.index += 1; true },
+		}
+	}
+
+	/// Move the cursor one step backward. `O(1)`.
+	/// Returns `false` if the cursor could not be moved.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// list.move_to_back();
+	///
+	/// assert!(list.retreat());
+	/// assert_eq!(list.current(), Some(&2));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn retreat(&mut self) -> bool {
+		if self.len == 0 { return false; }
+
+		match self.node(self.current).prev {
+			NIL  => false,
+			prev => { self.current = prev; self.index -= 1; true },
+		}
+	}
+
+	/// Move the cursor by a given offset. `O(n)`.
+	/// If the offset is out of bounds the cursor will be moved to the edge,
+	/// and `false` will be returned.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	///
+	/// assert!(list.move_by(2));
+	/// assert_eq!(list.current(), Some(&3));
+	///
+	/// assert!(!list.move_by(-10));
+	/// assert_eq!(list.index(), 0);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn move_by(&mut self, offset: isize) -> bool {
+		match offset.cmp(&0) {
+			Ordering::Greater => (0..offset ).fold(true, |_, _| self.advance()),
+			Ordering::Less    => (0..-offset).fold(true, |_, _| self.retreat()),
+			Ordering::Equal   => true,
+		}
+	}
+
+	/// Get a ref to an element at the given offset from the cursor. `O(n)`.
+	/// Returns `None` if the offset is out of bounds.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// assert_eq!(list.get(1), Some(&2));
+	/// assert_eq!(list.get(-1), None);
+	/// ```
+	#[inline]
+	pub fn get(&self, offset: isize) -> Option<&T> {
+		self.get_raw(offset).map(|node| &self.node(node).elem)
+	}
+
+	/// Get a mut ref to an element at the given offset from the cursor. `O(n)`.
+	/// Returns `None` if the offset is out of bounds.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	///
+	/// *list.get_mut(1).unwrap() = 4;
+	/// assert_eq!(format!("{:?}", list), "[1, 4, 3]");
+	/// ```
+	#[inline]
+	pub fn get_mut(&mut self, offset: isize) -> Option<&mut T> {
+		self.get_raw(offset).map(|node| &mut self.node_mut(node).elem)
+	}
+
+	/// Get a ref to the element at an absolute index. `O(n)`.
+	/// Walks from whichever of the front, the back or the cursor is closest.
+	/// Returns `None` if the index is out of bounds.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3, 4]);
+	/// list.move_to_back();
+	///
+	/// assert_eq!(list.nth_abs(1), Some(&2));
+	/// assert_eq!(list.nth_abs(4), None);
+	/// ```
+	#[inline]
+	pub fn nth_abs(&self, index: usize) -> Option<&T> {
+		self.node_at(index, (self.index, self.current)).map(|node| &self.node(node).elem)
+	}
+
+	/// Get a mut ref to the element at an absolute index. `O(n)`.
+	/// Walks from whichever of the front, the back or the cursor is closest.
+	/// Returns `None` if the index is out of bounds.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	///
+	/// *list.nth_abs_mut(2).unwrap() = 4;
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 4]");
+	/// ```
+	#[inline]
+	pub fn nth_abs_mut(&mut self, index: usize) -> Option<&mut T> {
+		self.node_at(index, (self.index, self.current)).map(|node| &mut self.node_mut(node).elem)
+	}
+
+	/// Remove the current element and return it. `O(1)`.
+	/// The cursor will then point to the next element.
+	/// If the removed element was at the end of the list, the cursor will point to the previous
+	/// element and `false` will be returned.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// assert_eq!(list.consume_forward(), Some((1, true)));
+	/// assert_eq!(&format!("{:?}", list), "[2, 3]");
+	/// assert_eq!(list.current(), Some(&2));
+	/// ```
+	pub fn consume_forward(&mut self) -> Option<(T, bool)> {
+		if self.len == 0 { return None; }
+
+		let node = self.unlink(self.current);
+		match node.next {
+			NIL => {
+				self.current = self.back;
+				self.index   = self.index.saturating_sub(1);
+				Some((node.elem, false))
+			},
+			next => {
+				self.current = next;
+				Some((node.elem, true))
+			},
+		}
+	}
+
+	/// Remove the current element and return it. `O(1)`.
+	/// The cursor will then point to the previous element.
+	/// If the removed element was at the front of the list, the cursor will point to the next
+	/// element and `false` will be returned.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// list.advance();
+	///
+	/// assert_eq!(list.consume_backward(), Some((2, true)));
+	/// assert_eq!(&format!("{:?}", list), "[1, 3]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	pub fn consume_backward(&mut self) -> Option<(T, bool)> {
+		if self.len == 0 { return None; }
+
+		let node = self.unlink(self.current);
+		match node.prev {
+			NIL => {
+				self.current = self.front;
+				Some((node.elem, false))
+			},
+			prev => {
+				self.current = prev;
+				self.index  -= 1;
+				Some((node.elem, true))
+			},
+		}
+	}
+
+	/// Replace the current element with a new one. `O(1)`.
+	/// Returns the old element.
+	/// If the list is empty, the new element will be inserted, and `None` returned.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// assert_eq!(list.replace_cursor(4), Some(1));
+	/// assert_eq!(format!("{:?}", list), "[4, 2, 3]");
+	/// ```
+	#[inline]
+	pub fn replace_cursor(&mut self, elem: T) -> Option<T> {
+		match self.len {
+			0 => { self.push_next(elem); None },
+			_ => Some(mem::replace(&mut self.node_mut(self.current).elem, elem)),
+		}
+	}
+
+	/// Split the list after the cursor. `O(n)`,
+	/// the split off elements are moved into an arena of their own.
+	/// If the list is empty, or the cursor is at the end, `None` will be returned.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// list.advance();
+	/// let new_list = list.split_after().unwrap();
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2]");
+	/// assert_eq!(format!("{:?}", new_list), "[3]");
+	/// assert_eq!(new_list.index(), 0);
+	/// ```
+	pub fn split_after(&mut self) -> Option<Self> {
+		if self.is_at_back() || self.is_empty() { return None; }
+
+		let mut new = Self::with_capacity(self.len - self.index - 1);
+		loop {
+			match self.node(self.current).next {
+				NIL  => return Some(new),
+				next => new.push_back(self.unlink(next).elem),
+			}
+		}
+	}
+
+	/// Split the list before the cursor. `O(n)`,
+	/// the split off elements are moved into an arena of their own.
+	/// If the list is empty, or the cursor is at the front, `None` will be returned.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3, 4]);
+	/// list.move_by(2);
+	/// let new_list = list.split_before().unwrap();
+	///
+	/// assert_eq!(format!("{:?}", list), "[3, 4]");
+	/// assert_eq!(format!("{:?}", new_list), "[1, 2]");
+	/// assert_eq!(new_list.index(), 1);
+	/// assert_eq!(list.index(), 0);
+	/// ```
+	pub fn split_before(&mut self) -> Option<Self> {
+		if self.is_at_front() || self.is_empty() { return None; }
+
+		let mut new = Self::with_capacity(self.index);
+		self.index = 0;
+		loop {
+			match self.node(self.current).prev {
+				NIL  => return Some(new),
+				prev => new.push_front(self.unlink(prev).elem),
+			}
+		}
+	}
+
+	/// Append another list to the back of this one. `O(m)`, `m` being the length of `other`,
+	/// whose elements are moved into this list's arena.
+	/// The cursor is left where it was, if this list was empty it takes over the cursor of `other`.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// list.advance();
+	/// list.append(IterList::from(vec![4, 5]));
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3, 4, 5]");
+	/// assert_eq!(list.current(), Some(&2));
+	/// assert_eq!(list.index(), 1);
+	/// ```
+	pub fn append(&mut self, other: Self) {
+		if self.is_empty() { *self = other; return; }
+
+		self.reserve(other.len);
+		other.into_iter().for_each(|elem| self.push_back(elem));
+	}
+
+	/// Prepend another list to the front of this one. `O(m)`, `m` being the length of `other`,
+	/// whose elements are moved into this list's arena.
+	/// The cursor stays on the same element, its index is shifted by the length of `other`.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![3, 4]);
+	/// list.prepend(IterList::from(vec![1, 2]));
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3, 4]");
+	/// assert_eq!(list.current(), Some(&3));
+	/// assert_eq!(list.index(), 2);
+	/// ```
+	pub fn prepend(&mut self, other: Self) {
+		if self.is_empty() { *self = other; return; }
+
+		self.reserve(other.len);
+		other.into_iter().rev().for_each(|elem| self.push_front(elem));
+	}
+
+	/// Get a ref to the current element. `O(1)`.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	#[inline]
+	pub fn current(&self) -> Option<&T> {
+		self.is_empty().not().then(|| &self.node(self.current).elem)
+	}
+
+	/// Get a mut ref to the current element. `O(1)`.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	///
+	/// *list.get_current_mut().unwrap() = 4;
+	/// assert_eq!(format!("{:?}", list), "[4, 2, 3]");
+	/// ```
+	#[inline]
+	pub fn get_current_mut(&mut self) -> Option<&mut T> {
+		let current = self.current;
+		self.is_empty().not().then(|| &mut self.node_mut(current).elem)
+	}
+
+	/// Get a ref to the first element. `O(1)`.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// list.move_to_back();
+	///
+	/// assert_eq!(list.front(), Some(&1));
+	/// ```
+	#[inline]
+	pub fn front(&self) -> Option<&T> {
+		self.is_empty().not().then(|| &self.node(self.front).elem)
+	}
+
+	/// Get a mut ref to the first element. `O(1)`.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	///
+	/// *list.front_mut().unwrap() = 4;
+	/// assert_eq!(format!("{:?}", list), "[4, 2, 3]");
+	/// ```
+	#[inline]
+	pub fn front_mut(&mut self) -> Option<&mut T> {
+		let front = self.front;
+		self.is_empty().not().then(|| &mut self.node_mut(front).elem)
+	}
+
+	/// Get a ref to the last element. `O(1)`.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	///
+	/// assert_eq!(list.back(), Some(&3));
+	/// ```
+	#[inline]
+	pub fn back(&self) -> Option<&T> {
+		self.is_empty().not().then(|| &self.node(self.back).elem)
+	}
+
+	/// Get a mut ref to the last element. `O(1)`.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	///
+	/// *list.back_mut().unwrap() = 4;
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 4]");
+	/// ```
+	#[inline]
+	pub fn back_mut(&mut self) -> Option<&mut T> {
+		let back = self.back;
+		self.is_empty().not().then(|| &mut self.node_mut(back).elem)
+	}
+
+	/// Get the number of elements in the list. `O(1)`.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// assert_eq!(list.len(), 3);
+	/// ```
+	#[inline]
+	pub const fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Check if the list is empty. `O(1)`.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let list: IterList<u8> = IterList::new();
+	/// assert!(list.is_empty());
+	/// ```
+	#[inline]
+	pub const fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Get the index of the cursor. `O(1)`.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// assert_eq!(list.index(), 0);
+	/// ```
+	#[inline]
+	pub const fn index(&self) -> usize {
+		self.index
+	}
+
+	/// Provides a copy of the current cursor. `O(1)`.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// let mut cursor = list.as_cursor();
+	///
+	/// assert_eq!(cursor.next(), Some(&1));
+	/// assert_eq!(cursor.next(), Some(&2));
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	#[inline]
+	pub fn as_cursor(&self) -> Cursor<'_, T> {
+		Cursor { list: self, current: self.current, index: self.index }
+	}
+
+	/// Iterate over the whole list from front to back, wherever the cursor is. `O(1)`.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// list.move_to_back();
+	///
+	/// assert_eq!(list.iter().collect::<Vec<_>>(), [&1, &2, &3]);
+	/// assert_eq!(list.iter().rev().next(), Some(&3));
+	/// ```
+	#[inline]
+	pub fn iter(&self) -> Iter<'_, T> {
+		Iter { list: self, front: self.front, back: self.back, len: self.len }
+	}
+
+	#[inline]
+	fn node(&self, node: u32) -> &Node<T> {
+		match &self.nodes[node as usize] {
+			Slot::Used(node) => node,
+			Slot::Free(_) => unreachable!("link to a free slot"),
+		}
+	}
+
+	#[inline]
+	fn node_mut(&mut self, node: u32) -> &mut Node<T> {
+		match &mut self.nodes[node as usize] {
+			Slot::Used(node) => node,
+			Slot::Free(_) => unreachable!("link to a free slot"),
+		}
+	}
+
+	/// Walk `offset` steps away from a node. `O(offset)`.
+	fn walk(&self, mut node: u32, offset: isize) -> u32 {
+		for _ in 0..offset.unsigned_abs() {
+			node = if offset < 0 { self.node(node).prev } else { self.node(node).next };
+		}
+		node
+	}
+
+	/// Find the node at an absolute index. `O(n)`.
+	/// Walks from whichever of the front, the back or `from` (an index and its node) is closest.
+	fn node_at(&self, index: usize, from: (usize, u32)) -> Option<u32> {
+		if index >= self.len { return None; }
+
+		[(0, self.front), (self.len - 1, self.back), from].into_iter()
+			.min_by_key(|(i, _)| i.abs_diff(index))
+			.map(|(i, node)| self.walk(node, index as isize - i as isize))
+	}
+
+	fn get_raw(&self, offset: isize) -> Option<u32> {
+		self.index.checked_add_signed(offset)
+			.and_then(|index| self.node_at(index, (self.index, self.current)))
+	}
+
+	/// Put a node into a free slot, or a new one at the end of the arena. `O(1)`, amortized if the arena grows.
+	fn alloc(&mut self, node: Node<T>) -> u32 {
+		if self.free == NIL {
+			let index = u32::try_from(self.nodes.len()).ok()
+				.filter(|&i| i != NIL)
+				.expect("arena IterList is full");
+			self.nodes.push(Slot::Used(node));
+			return index;
+		}
+
+		let index = self.free;
+		match mem::replace(&mut self.nodes[index as usize], Slot::Used(node)) {
+			Slot::Free(next) => self.free = next,
+			Slot::Used(_) => unreachable!("free list points to a used slot"),
+		}
+		index
+	}
+
+	/// Detach a node from its neighbours and free its slot, fixing up both ends and the length. `O(1)`.
+	/// The cursor is left alone, so it is up to the caller to move it off `node`.
+	fn unlink(&mut self, node: u32) -> Node<T> {
+		let Slot::Used(removed) = mem::replace(&mut self.nodes[node as usize], Slot::Free(self.free))
+			else { unreachable!("unlinking a free slot") };
+		self.free = node;
+
+		match removed.prev {
+			NIL  => self.front = removed.next,
+			prev => self.node_mut(prev).next = removed.next,
+		}
+		match removed.next {
+			NIL  => self.back = removed.prev,
+			next => self.node_mut(next).prev = removed.prev,
+		}
+
+		self.len -= 1;
+		if self.len == 0 {
+			// nothing left to link to, start the arena over, keeping its memory
+			self.nodes.clear();
+			self.free = NIL;
+		}
+		removed
+	}
+
+	/// Insert the only element of an empty list, pointing the cursor and both ends at it.
+	fn reset_to(&mut self, elem: T) {
+		let node = self.alloc(Node { next: NIL, prev: NIL, elem });
+		self.current = node;
+		self.front   = node;
+		self.back    = node;
+		self.index   = 0;
+		self.len     = 1;
+	}
+}
+
+impl<T> Default for IterList<T> {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T> std::ops::Index<isize> for IterList<T> {
+	type Output = T;
+
+	/// Essentially equivalent to `get`. `O(n)`.
+	/// # Panics
+	/// Panics if the index is out of bounds.
+	#[inline]
+	fn index(&self, index: isize) -> &Self::Output {
+		self.get(index).expect("Index out of bounds")
+	}
+}
+
+impl<T> std::ops::IndexMut<isize> for IterList<T> {
+	#[inline]
+	fn index_mut(&mut self, index: isize) -> &mut Self::Output {
+		self.get_mut(index).expect("Index out of bounds")
+	}
+}
+
+impl<T: Clone> Clone for IterList<T> {
+	/// Clone the list. `O(n)`.
+	/// The arena is copied as is, free slots and all, so the cursor position is retained.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// list.advance();
+	///
+	/// let cloned = list.clone();
+	/// assert_eq!(format!("{:?}", cloned), "[1, 2, 3]");
+	/// assert_eq!(cloned.current(), Some(&2));
+	/// ```
+	fn clone(&self) -> Self {
+		Self { nodes: self.nodes.clone(), ..*self }
+	}
+}
+
+impl<T: Debug> Debug for IterList<T> {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		f.debug_list().entries(self.iter()).finish()
+	}
+}
+
+
+/*
+* ==========================
+* ===== Iteratory bits =====
+* ==========================
+*/
+
+impl<T> From<Vec<T>> for IterList<T> {
+	/// Create a new list from a Vec. `O(n)`.
+	/// Cursor is set to the front of the list.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	#[inline]
+	fn from(vec: Vec<T>) -> Self {
+		vec.into_iter().collect()
+	}
+}
+
+impl<T: Clone> From<&[T]> for IterList<T> {
+	/// Create a new list from a slice. `O(n)`.
+	/// Cursor is set to the front of the list.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let array: &[u8] = &[1, 2, 3];
+	/// let list = IterList::from(array);
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+	/// ```
+	#[inline]
+	fn from(slice: &[T]) -> Self {
+		slice.iter().cloned().collect()
+	}
+}
+
+impl<T> FromIterator<T> for IterList<T> {
+	/// Create a new list from an iterator. `O(n)`.
+	/// Cursor is set to the front of the list.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let list = (1..=3).collect::<IterList<_>>();
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		let iter = iter.into_iter();
+		let mut list = Self::with_capacity(iter.size_hint().0);
+		iter.for_each(|elem| list.push_back(elem));
+		list
+	}
+}
+
+/// A borrowing iterator over the whole list, front to back. Made by `IterList::iter`.
+pub struct Iter<'a, T> {
+	list:  &'a IterList<T>,
+	front: u32,
+	back:  u32,
+	len:   usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+	type Item = &'a T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.len == 0 { return None; }
+		self.len -= 1;
+
+		let node = self.list.node(self.front);
+		self.front = node.next;
+		Some(&node.elem)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>)
+		{ (self.len, Some(self.len)) }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.len == 0 { return None; }
+		self.len -= 1;
+
+		let node = self.list.node(self.back);
+		self.back = node.prev;
+		Some(&node.elem)
+	}
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+	#[inline]
+	fn clone(&self) -> Self {
+		Self { ..*self }
+	}
+}
+
+impl<'a, T> IntoIterator for &'a IterList<T> {
+	type Item     = &'a T;
+	type IntoIter = Iter<'a, T>;
+
+	/// Same as `IterList::iter`. `O(1)`.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	///
+	/// let mut sum = 0;
+	/// for elem in &list { sum += elem; }
+	/// assert_eq!(sum, 6);
+	/// ```
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/// An owning iterator over the list, front to back. Made by `IterList::into_iter`.
+/// Elements that aren't yielded are dropped along with it.
+pub struct IntoIter<T> {
+	list: IterList<T>,
+}
+
+impl<T> IntoIterator for IterList<T> {
+	type Item     = T;
+	type IntoIter = IntoIter<T>;
+
+	/// Turn the list into an iterator over its elements, front to back, wherever the cursor is. `O(1)`.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3, 4]);
+	/// list.move_to_back();
+	///
+	/// let mut iter = list.into_iter();
+	/// assert_eq!(iter.next_back(), Some(4));
+	/// assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3]);
+	/// ```
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		IntoIter { list: self }
+	}
+}
+
+impl<T> Iterator for IntoIter<T> {
+	type Item = T;
+
+	/// Internally this call is just `pop_front`. `O(1)`.
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.list.pop_front()
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>)
+		{ (self.list.len, Some(self.list.len)) }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+	/// Internally this call is just `pop_back`. `O(1)`.
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.list.pop_back()
+	}
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+impl<T> FusedIterator for IntoIter<T> {}
+
+
+/*
+* =======================
+* ===== Cursor bits =====
+* =======================
+*/
+
+/// A copy of a cursor of an arena IterList.
+/// Allows for traversing the list without modifying the original.
+/// It's an index into the arena plus a ref to the list, so it can't outlive or go out of sync with it.
+/// ```
+/// # use iterlist::arena::IterList;
+/// let list = IterList::from(vec![1, 2, 3]);
+/// let mut cursor = list.as_cursor();
+///
+/// assert_eq!(cursor.next(), Some(&1));
+/// assert_eq!(cursor.next(), Some(&2));
+/// assert_eq!(cursor.current(), Some(&3));
+///
+/// assert_eq!(list.current(), Some(&1));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Cursor<'i, T> {
+	list:    &'i IterList<T>,
+	current: u32,
+	index:   usize,
+}
+
+impl<'i, T> Iterator for Cursor<'i, T> {
+	type Item = &'i T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.current == NIL { return None; }
+
+		let node = self.list.node(self.current);
+		self.current = node.next;
+		self.index  += 1;
+		Some(&node.elem)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let left = if self.current == NIL { 0 } else { self.list.len - self.index };
+		(left, Some(left))
+	}
+}
+
+impl<'t, T> Cursor<'t, T> {
+	/// Create a new cursor from an IterList. `O(1)`.
+	/// The new cursor is an exact copy of the list's cursor.
+	#[inline]
+	pub fn from(list: &'t IterList<T>) -> Self {
+		list.as_cursor()
+	}
+
+	/// Update the cursor to match the current state of the list. `O(1)`.
+	/// Useful if you want to use the same cursor on multiple lists.
+	#[inline]
+	pub fn reacquire(&mut self, list: &'t IterList<T>) {
+		*self = list.as_cursor();
+	}
+
+	/// Get a ref to the current element. `O(1)`.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// let cursor = list.as_cursor();
+	///
+	/// assert_eq!(cursor.current(), Some(&1));
+	/// ```
+	#[inline]
+	pub fn current(&self) -> Option<&'t T> {
+		(self.current != NIL).then(|| &self.list.node(self.current).elem)
+	}
+
+	/// Get the index of the cursor. `O(1)`.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// let mut cursor = list.as_cursor();
+	///
+	/// cursor.next();
+	/// assert_eq!(cursor.index(), 1);
+	/// ```
+	#[inline]
+	pub const fn index(&self) -> usize {
+		self.index
+	}
+
+	/// Move the cursor to the front of the list. `O(1)`.
+	/// Returns the number of elements skipped over.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// let mut cursor = list.as_cursor();
+	/// cursor.move_to_back();
+	///
+	/// assert_eq!(cursor.move_to_front(), 2);
+	/// assert_eq!(cursor.current(), Some(&1));
+	/// ```
+	pub fn move_to_front(&mut self) -> usize {
+		self.current = self.list.front;
+		mem::take(&mut self.index)
+	}
+
+	/// Move the cursor to the back of the list. `O(1)`.
+	/// Returns the number of elements skipped over.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// let mut cursor = list.as_cursor();
+	///
+	/// assert_eq!(cursor.move_to_back(), 2);
+	/// assert_eq!(cursor.current(), Some(&3));
+	/// ```
+	pub fn move_to_back(&mut self) -> usize {
+		if self.list.is_empty() { return 0; }
+
+		let skipped = (self.list.len - 1).saturating_sub(self.index);
+		self.current = self.list.back;
+		self.index   = self.list.len - 1;
+		skipped
+	}
+
+	/// Move the cursor to the specified index. `O(n)`.
+	/// Walks from whichever of the front, the back or the cursor is closest.
+	/// If the index is out of bounds the cursor will be moved to the edge,
+	/// and `false` will be returned.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// let mut cursor = list.as_cursor();
+	///
+	/// assert!(cursor.move_to(1));
+	/// assert_eq!(cursor.current(), Some(&2));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn move_to(&mut self, index: usize) -> bool {
+		let from = match self.current {
+			NIL => (0, self.list.front),
+			current => (self.index, current),
+		};
+
+		match self.list.node_at(index, from) {
+			Some(node) => {
+				self.current = node;
+				self.index   = index;
+				true
+			},
+			None => { self.move_to_back(); false },
+		}
+	}
+
+	/// Move the cursor one step forward. `O(1)`.
+	/// Returns `false` if the cursor could not be moved.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// let mut cursor = list.as_cursor();
+	///
+	/// assert!(cursor.advance());
+	/// assert_eq!(cursor.current(), Some(&2));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn advance(&mut self) -> bool {
+		if self.current == NIL { return false; }
+
+		match self.list.node(self.current).next {
+			NIL  => false,
+			next => { self.current = next; self.index += 1; true },
+		}
+	}
+
+	/// Move the cursor one step backward. `O(1)`.
+	/// Returns `false` if the cursor could not be moved.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// let mut cursor = list.as_cursor();
+	/// cursor.move_to_back();
+	///
+	/// assert!(cursor.retreat());
+	/// assert_eq!(cursor.current(), Some(&2));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn retreat(&mut self) -> bool {
+		if self.current == NIL { return false; }
+
+		match self.list.node(self.current).prev {
+			NIL  => false,
+			prev => { self.current = prev; self.index -= 1; true },
+		}
+	}
+
+	/// Move the cursor by a given offset. `O(n)`.
+	/// If the offset is out of bounds the cursor will be moved to the edge,
+	/// and `false` will be returned.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// let mut cursor = list.as_cursor();
+	///
+	/// assert!(cursor.move_by(2));
+	/// assert_eq!(cursor.index(), 2);
+	///
+	/// assert!(!cursor.move_by(-10));
+	/// assert_eq!(cursor.index(), 0);
+	/// ```
+	#[inline]
+	pub fn move_by(&mut self, offset: isize) -> bool {
+		match offset.cmp(&0) {
+			Ordering::Greater => (0..offset ).fold(true, |_, _| self.advance()),
+			Ordering::Less    => (0..-offset).fold(true, |_, _| self.retreat()),
+			Ordering::Equal   => true,
+		}
+	}
+
+	/// Get a ref to an element at the given offset. `O(n)`.
+	/// Returns `None` if the offset is out of bounds.
+	/// ```
+	/// # use iterlist::arena::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// let cursor = list.as_cursor();
+	///
+	/// assert_eq!(cursor.get(1), Some(&2));
+	/// assert_eq!(cursor.get(-1), None);
+	/// ```
+	pub fn get(&self, offset: isize) -> Option<&'t T> {
+		if self.current == NIL { return None; }
+
+		self.index.checked_add_signed(offset)
+			.and_then(|index| self.list.node_at(index, (self.index, self.current)))
+			.map(|node| &self.list.node(node).elem)
+	}
+}
+
+impl<T> std::ops::Deref for Cursor<'_, T> {
+	type Target = T;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		self.current().unwrap()
+	}
+}
+
+impl<T> std::ops::Index<isize> for Cursor<'_, T> {
+	type Output = T;
+
+	/// Essentially equivalent to `get`. `O(n)`.
+	/// # Panics
+	/// Panics if the index is out of bounds.
+	#[inline]
+	fn index(&self, index: isize) -> &Self::Output {
+		self.get(index).unwrap_or_else(|| panic!("Index out of bounds"))
+	}
+}
+
+impl<T: Debug> Debug for Cursor<'_, T> {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		write!(f, "{}: {:?}", self.index, self.current())
+	}
+}
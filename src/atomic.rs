@@ -1,3 +1,5 @@
+#![allow(clippy::result_unit_err, clippy::double_must_use)]
+
 use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering::*};
 use std::marker::PhantomData;
 use std::ptr;
@@ -5,8 +7,6 @@ use std::cmp::Ordering;
 use std::mem;
 use std::fmt::Debug;
 
-compile_error!("The atomic module has not been fully tested and may be borked :p\n I dont *fully* know how to do this, if ye do, let me know :)");
-
 /// an Atomic version of IterList.
 pub struct IterList<T> {
     current: AtomicPtr<Node<T>>,
@@ -419,7 +419,7 @@ impl<T> IterList<T> {
     /// *list.get_mut(1).unwrap().unwrap() = 4;
     /// assert_eq!(format!("{:?}", list), "[1, 4, 3]");
     /// ```
-    pub fn get_mut(&self, offset: isize) -> Result<Option<&mut T>, ()> {
+    pub fn get_mut(&mut self, offset: isize) -> Result<Option<&mut T>, ()> {
         let index = self.index.load(Relaxed);
         offset.checked_abs()
             .and_then(|s| (s > index as isize).into())
@@ -591,6 +591,74 @@ impl<T> IterList<T> {
         }
     }
 
+    /// Append another list to the back of this one. `O(n)`.  
+    /// Neither list knows where its ends are, so both are walked to find them,
+    /// after that only the boundary nodes are relinked.  
+    /// The cursor is left where it was, if this list was empty it takes over the cursor of `other`.
+    /// ```
+    /// # use iterlist::atomic::IterList;
+    /// let mut list = IterList::from(vec![1, 2, 3]);
+    /// let _ = list.advance();
+    /// list.append(IterList::from(vec![4, 5]));
+    ///
+    /// assert_eq!(format!("{:?}", list), "[1, 2, 3, 4, 5]");
+    /// assert_eq!(list.get_cursor(), Some(&2));
+    /// assert_eq!(list.index(), 1);
+    /// assert_eq!(list.len(), 5);
+    /// ```
+    pub fn append(&mut self, other: Self) {
+        if other.current.load(Acquire).is_null() { return; }
+        if self.current.load(Acquire).is_null() { *self = other; return; }
+
+        unsafe {
+            let mut back  = self.current.load(Acquire);
+            let mut front = other.current.swap(ptr::null_mut(), AcqRel);
+
+            while let Some(next) = (*back).next.load_ptr(Acquire) { back = next; }
+            while let Some(prev) = (*front).prev.load_ptr(Acquire) { front = prev; }
+
+            (*back).next.store(front, Release);
+            (*front).prev.store(back, Release);
+        }
+
+        self.len.fetch_add(other.len.load(Relaxed), AcqRel);
+    }
+
+    /// Prepend another list to the front of this one. `O(n)`.  
+    /// Like `append`, both lists are walked to find their ends before relinking them.  
+    /// The cursor stays on the same element, its index is shifted by the length of `other`.
+    /// ```
+    /// # use iterlist::atomic::IterList;
+    /// let mut list = IterList::from(vec![3, 4]);
+    /// list.prepend(IterList::from(vec![1, 2]));
+    ///
+    /// assert_eq!(format!("{:?}", list), "[1, 2, 3, 4]");
+    /// assert_eq!(list.get_cursor(), Some(&3));
+    /// assert_eq!(list.index(), 2);
+    ///
+    /// list.prepend(IterList::new());
+    /// assert_eq!(list.len(), 4);
+    /// ```
+    pub fn prepend(&mut self, other: Self) {
+        if other.current.load(Acquire).is_null() { return; }
+        if self.current.load(Acquire).is_null() { *self = other; return; }
+
+        unsafe {
+            let mut front = self.current.load(Acquire);
+            let mut back  = other.current.swap(ptr::null_mut(), AcqRel);
+
+            while let Some(prev) = (*front).prev.load_ptr(Acquire) { front = prev; }
+            while let Some(next) = (*back).next.load_ptr(Acquire) { back = next; }
+
+            (*back).next.store(front, Release);
+            (*front).prev.store(back, Release);
+        }
+
+        let len = other.len.load(Relaxed);
+        self.len.fetch_add(len, AcqRel);
+        self.index.fetch_add(len, AcqRel);
+    }
+
     /// Get a ref to the current element. `O(1)`.
     /// ```
     /// # use iterlist::atomic::IterList;
@@ -612,7 +680,7 @@ impl<T> IterList<T> {
     /// assert_eq!(format!("{:?}", list), "[4, 2, 3]");
     /// ```
     #[inline]
-    pub fn get_cursor_mut(&self) -> Option<&mut T> {
+    pub fn get_cursor_mut(&mut self) -> Option<&mut T> {
         unsafe { self.current.load_ptr(Acquire).map(|c| &mut (*c).elem ) }
     }
 
@@ -662,11 +730,11 @@ impl<T> IterList<T> {
     /// assert_eq!(list.get_cursor(), Some(&1));
     /// ```
     #[inline]
-    pub fn as_cursor(&self) -> Cursor<T> {
+    pub fn as_cursor(&self) -> Cursor<'_, T> {
         Cursor {
             _list:   PhantomData,
             index:   self.index.load(Relaxed).into(),
-            current: (&self.current).into(),
+            current: self.current.load(Acquire).into(),
         }
     }
 }
@@ -831,7 +899,7 @@ impl<T> FromIterator<T> for IterList<T> {
 /// Internally, the cursor is a fat pointer to the current element,
 /// so the size of a `Cursor` is two words.  
 /// ```
-/// # use iterlist::IterList;
+/// # use iterlist::atomic::IterList;
 /// let list = IterList::from(vec![1, 2, 3]);
 /// let mut cursor = list.as_cursor();
 ///
@@ -843,7 +911,7 @@ impl<T> FromIterator<T> for IterList<T> {
 /// ```
 // #[derive(Clone, Copy)]
 pub struct Cursor<'i, T> {
-    current: &'i AtomicPtr<Node<T>>,
+    current: AtomicPtr<Node<T>>,
     index:   AtomicUsize,
     _list:   PhantomData<&'i T>,
 }
@@ -866,58 +934,12 @@ impl<'i, T> Iterator for Cursor<'i, T> {
     }
 }
 
-impl<'t, T: Send + Sync> Cursor<'t, T> {
-    /// Get a mut ref to the current element. `O(1)`.
-    /// ```
-    /// # use iterlist::atomic::IterList;
-    /// let list = IterList::from(vec![1, 2, 3]);
-    /// let cursor = list.as_cursor();
-    ///
-    /// *cursor.get_cursor_mut().unwrap() = 4;
-    /// assert_eq!(format!("{:?}", list), "[4, 2, 3]");
-    /// ```
-    #[inline]
-    pub fn get_cursor_mut(&self) -> Option<&mut T> {
-        unsafe { self.current.load_ptr(Acquire).map(|c| &mut (*c).elem ) }
-    }
-
-
-    /// Get a mut ref to an element at the given offset. `O(n)`.
-    /// Returns `None` if the offset is out of bounds, or if another thread modified the value
-    /// during the function's run time.
-    /// ```
-    /// # use iterlist::atomic::IterList;
-    /// let list = IterList::from(vec![1, 2, 3]);
-    /// let cursor = list.as_cursor();
-    ///
-    /// *cursor.get_mut(1).unwrap().unwrap() = 4;
-    /// assert_eq!(format!("{:?}", list), "[1, 4, 3]");
-    /// ```
-    pub fn get_mut(&self, offset: isize) -> Result<Option<&mut T>, ()> {
-        offset.checked_abs().and_then(|s| (s > self.index.load(Relaxed) as isize).into()).ok_or(())?;
-
-        let current = self.current.load_ptr(Acquire).ok_or(())?;
-        let mut ptr = current;
-
-        unsafe {
-            match offset.cmp(&0) {
-                Ordering::Greater => (0.. offset).for_each(|_| {ptr.as_ref().map(|c| ptr = c.next.load(Acquire)); }),
-                Ordering::Less    => (0..-offset).for_each(|_| {ptr.as_ref().map(|c| ptr = c.prev.load(Acquire)); }),
-                Ordering::Equal   => return Ok(self.get_cursor_mut()),
-            }
-
-            if self.current.load(Acquire) != current { return Err(()); }
-            Ok(ptr.as_mut().map(|c| &mut c.elem ))
-        }
-    }
-}
-
 impl<'t, T> Cursor<'t, T> {
     /// Update the cursor to match the current state of the list. `O(1)`.  
     /// Useful if you lose track of the list, or want to use the same cursor on multiple lists.
     #[inline]
     pub fn reacquire(&mut self, list: &'t IterList<T>) {
-        self.current = (&list.current).into();
+        self.current = list.current.load(Acquire).into();
         self.index   = list.index.load(Relaxed).into();
     }
 
@@ -951,7 +973,7 @@ impl<'t, T> Cursor<'t, T> {
     /// Move the cursor to the front of the list. `O(n)`.  
     /// Returns the number of elements traversed.
     /// ```
-    /// # use iterlist::IterList;
+    /// # use iterlist::atomic::IterList;
     /// let list = IterList::from(vec![1, 2, 3]);
     /// let mut cursor = list.as_cursor();
     ///
@@ -980,7 +1002,7 @@ impl<'t, T> Cursor<'t, T> {
     /// Move the cursor to the back of the list. `O(n)`.  
     /// Returns the number of elements traversed.
     /// ```
-    /// # use iterlist::IterList;
+    /// # use iterlist::atomic::IterList;
     /// let list = IterList::from(vec![1, 2, 3]);
     /// let mut cursor = list.as_cursor();
     ///
@@ -1006,7 +1028,7 @@ impl<'t, T> Cursor<'t, T> {
     /// Returns the number of elements traversed. It is up to the user to check if that number is correct.  
     /// Returns `Err(n)` if the cursor could not be moved at any point  
     /// ```
-    /// # use iterlist::IterList;
+    /// # use iterlist::atomic::IterList;
     /// let list = IterList::from(vec![1, 2, 3]);
     /// let mut cursor = list.as_cursor();
     ///
@@ -1044,7 +1066,7 @@ impl<'t, T> Cursor<'t, T> {
     /// Returns `false` if the cursor is at the edge.
     /// Returns `Err(())` if the cursor could not be moved.
     /// ```
-    /// # use iterlist::IterList;
+    /// # use iterlist::atomic::IterList;
     /// let list = IterList::from(vec![1, 2, 3]);
     /// let mut cursor = list.as_cursor();
     ///
@@ -1072,7 +1094,7 @@ impl<'t, T> Cursor<'t, T> {
     /// Returns `false` if the cursor is at the edge,
     /// Returns `Err(())` if the cursor could not be moved.
     /// ```
-    /// # use iterlist::IterList;
+    /// # use iterlist::atomic::IterList;
     /// let list = IterList::from(vec![1, 2, 3]);
     /// let mut cursor = list.as_cursor();
     ///
@@ -1101,7 +1123,7 @@ impl<'t, T> Cursor<'t, T> {
     /// If the offset is out of bounds the cursor will be moved to the edge, 
     /// and `false` will be returned.
     /// ```
-    /// # use iterlist::IterList;
+    /// # use iterlist::atomic::IterList;
     /// let list = IterList::from(vec![1, 2, 3]);
     /// let mut cursor = list.as_cursor();
     ///
@@ -1144,13 +1166,13 @@ impl<'t, T> Cursor<'t, T> {
     /// Get a ref to an element at the given offset. `O(n)`.
     /// Returns `None` if the offset is out of bounds.
     /// ```
-    /// # use iterlist::IterList;
+    /// # use iterlist::atomic::IterList;
     /// let list = IterList::from(vec![1, 2, 3]);
     /// let mut cursor = list.as_cursor();
     ///
     /// assert_eq!(cursor.get_cursor(), Some(&1));
-    /// assert_eq!(cursor.get(1), Some(&2));
-    /// assert_eq!(cursor.get(-1), None);
+    /// assert_eq!(cursor.get(1), Ok(Some(&2)));
+    /// assert_eq!(cursor.get(-1), Ok(None));
     /// ```
     pub fn get(&self, offset: isize) -> Result<Option<&T>, ()> {
         offset.checked_abs().and_then(|s| (s > self.index.load(Relaxed) as isize).into()).ok_or(())?;
@@ -1200,7 +1222,7 @@ impl<'i, T> std::ops::Index<isize> for Cursor<'i, T> {
 
 impl<T: Debug> Debug for Cursor<'_, T> {
     /// ```
-    /// # use iterlist::IterList;
+    /// # use iterlist::atomic::IterList;
     /// let list = IterList::from(vec![1, 2, 3]);
     /// let mut cursor = list.as_cursor();
     /// assert_eq!(format!("{:?}", cursor), "0: Some(1)");
@@ -1,11 +1,12 @@
 //! A doubly linked list with a cursor based api.  
-//! *it's also an iterator!*  
+//! *iterate it front to back with `iter`/`into_iter`!*  
 //! 
 //! `O(1)` pretty much everything (at the cursor).  
 //! 
 //! ## Example
 //! 
 //! ```rust
+//! # #[cfg(not(feature = "nightly"))] {
 //! use iterlist::IterList;
 //! 
 //! let mut list = IterList::new();
@@ -35,12 +36,14 @@
 //! 
 //! assert_eq!(format!("{:?}", list), "[-1, 1, 3]");
 //! 
-//! let num = list.fold(0, |acc, elem| acc + elem);
+//! let num = list.into_iter().fold(0, |acc, elem| acc + elem);
 //! 
 //! assert_eq!(num, 3);
+//! # }
 //! ```
 
 // #![feature(min_specialization)]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
 
 #![allow(forbidden_lint_groups)]
 #![forbid(clippy::all)]
@@ -49,10 +52,16 @@
 #[cfg(feature = "atomic")]
 pub mod atomic;
 
+#[cfg(feature = "arena")]
+pub mod arena;
+
+#[cfg(feature = "unrolled")]
+pub mod unrolled;
+
 #[cfg(not(feature = "nightly"))]
 mod list;
 #[cfg(not(feature = "nightly"))]
-pub use list::{IterList, Cursor};
+pub use list::{IterList, Cursor, CursorError, CursorMut, ListToken, TokenCursor, Splice, Side, Keep, Rev, NodeHandle, NodePool, Iter, IterMut, IntoIter, Drain, ExtractIf};
 
 #[cfg(feature = "nightly")]
 mod nightly;
@@ -3,14 +3,49 @@ use std::marker::PhantomData;
 use std::cmp::Ordering;
 use std::mem;
 use std::ptr::NonNull;
-use std::ops::Not;
+use std::ops::{Bound, Not, RangeBounds};
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use std::alloc::Layout;
+
+use allocator_api2::alloc::{AllocError, Allocator, Global};
+
+mod cursor_mut;
+pub use cursor_mut::CursorMut;
+
+mod token;
+pub use token::{ListToken, TokenCursor};
+
+mod handle;
+pub use handle::NodeHandle;
+
+mod rev;
+pub use rev::Rev;
+
+mod pool;
+pub use pool::NodePool;
+
+mod iter;
+pub use iter::{Iter, IterMut, IntoIter, Drain, ExtractIf};
+use handle::Handles;
 
 /// A doubly linked list. The `IterList` object is a fat pointer of a `Cursor + length`, which owns the underlying data.  
-/// This means the total stack size is 3 words; each element is 2 words + element size.
-pub struct IterList<T> {
+/// It also keeps pointers to both ends, so jumping to either of them is `O(1)`.  
+/// Handles given out by `handle` are tracked behind a single, lazily allocated pointer.  
+/// An id and a modification counter let `apply_cursor` check the cursors it is given,
+/// and one more pointer is taken by the optional `NodePool`.  
+/// Nodes are allocated in `A`, which takes no space at all for the default `Global`.  
+/// This means the stack size is nine pointer-sized fields on 64-bit plus the allocator; each element is 2 words + element size.
+pub struct IterList<T, A: Allocator = Global> {
 	current: NonNull<Node<T>>,
+	front:   NonNull<Node<T>>,
+	back:    NonNull<Node<T>>,
 	index:   usize,
 	len:     usize,
+	handles: Option<Box<Handles<T>>>,
+	pool:    Option<NodePool<T>>,
+	id:      AtomicU64,
+	version: u64,
+	alloc:   A,
 	_boo:    PhantomData<T>,
 }
 
@@ -21,19 +56,90 @@ struct Node<T> {
 }
 
 impl<T> Node<T> {
-	fn new_nonnull(elem: T) -> NonNull<Self> {
-		unsafe {
-			NonNull::new_unchecked(Box::into_raw(Box::new(Self {
-				next: None,
-				prev: None,
-				elem,
-			})))
+	/// Allocate a new unlinked node in `alloc`, handing the element back if that fails.
+	fn try_new_in(elem: T, alloc: &impl Allocator) -> Result<NonNull<Self>, (T, AllocError)> {
+		let node = match alloc.allocate(Layout::new::<Self>()) {
+			Ok(node) => node.cast::<Self>(),
+			Err(err) => return Err((elem, err)),
+		};
+		unsafe { node.as_ptr().write(Self { next: None, prev: None, elem }); }
+		Ok(node)
+	}
+
+	/// Follow `offset` links from `node`. `O(n)`.
+	///
+	/// # Safety
+	/// Every link along the way must exist.
+	unsafe fn walk(mut node: NonNull<Self>, offset: isize) -> NonNull<Self> {
+		match offset.cmp(&0) {
+			Ordering::Greater => (0.. offset).for_each(|_| node = node.as_ref().next.unwrap_unchecked()),
+			Ordering::Less    => (0..-offset).for_each(|_| node = node.as_ref().prev.unwrap_unchecked()),
+			Ordering::Equal   => (),
 		}
+		node
+	}
+}
+
+/// Lists get their id the first time one is needed, `0` means none yet.
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Why `IterList::apply_cursor` refused a cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorError {
+	/// The cursor was made by `Cursor::new`, `from_raw` or `new_dangling`, so it can't be checked.
+	Unchecked,
+	/// The cursor belongs to another list.
+	Foreign,
+	/// The list has been modified since the cursor was taken.
+	Stale,
+}
+
+impl std::fmt::Display for CursorError {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		f.write_str(match self {
+			Self::Unchecked => "cursor is not tied to a list",
+			Self::Foreign   => "cursor belongs to another list",
+			Self::Stale     => "list was modified since the cursor was taken",
+		})
 	}
 }
 
-unsafe impl<T: Send> Send for IterList<T> {}
-unsafe impl<T: Sync> Sync for IterList<T> {}
+impl std::error::Error for CursorError {}
+
+/// Where the cursor lands after splicing another list in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Splice {
+	/// Stay on the current element.
+	#[default]
+	Stay,
+	/// Move to the first inserted element.
+	First,
+	/// Move to the last inserted element.
+	Last,
+}
+
+/// Which side of the cursor an element goes on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Side {
+	/// Right after the cursor, like `insert_next`.
+	#[default]
+	Next,
+	/// Right before the cursor, like `insert_prev`.
+	Prev,
+}
+
+/// What the cursor sticks to when the list is reordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Keep {
+	/// Stay on the same element, wherever it ends up.
+	#[default]
+	Element,
+	/// Stay at the same index, on whichever element ends up there.
+	Index,
+}
+
+unsafe impl<T: Send, A: Allocator + Send> Send for IterList<T, A> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for IterList<T, A> {}
 
 impl<T> IterList<T> {
 	/// Create a new empty list. `O(1)`.  
@@ -45,20 +151,11 @@ impl<T> IterList<T> {
 	/// ```
 	#[inline]
 	pub const fn new() -> Self {
-		Self { 
-			current: NonNull::dangling(),
-			len:     0,
-			index:   0,
-			_boo:    PhantomData
-		}
+		Self::new_in(Global)
 	}
 
 	/// Create a new list with N zeroed elements. `O(n)`.  
-	/// *Secret Pro Tip:*  
-	/// This can potentially be a roundabout way to get a sort-of `with_capacity` method.
-	/// Although using it in such a way will rarely improve performance (iterlist never reallocates unlike `Vec`),
-	/// and can actually hurt it in most cases. That should only be done when
-	/// you have some very timing sensitive code, and you cant afford to have to allocate memory.
+	/// To get nodes ready ahead of time without creating any elements, use `with_capacity` instead.
 	///
 	/// # Safety
 	/// Type `T` must be safe to initialize as zeroed.
@@ -75,6 +172,57 @@ impl<T> IterList<T> {
 		})
 	}
 
+	/// Same as `collect`, but hands the element back instead of aborting if an allocation fails. `O(n)`.  
+	/// The elements collected so far are dropped with the partial list, the rest of `iter` is left alone.
+	/// Cursor is set to the front of the list.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let list = IterList::try_from_iter(1..=3).unwrap();
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, (T, AllocError)> {
+		let mut list = Self::new();
+		for elem in iter { list.try_push_next(elem)?; }
+		list.move_to_front();
+		Ok(list)
+	}
+}
+
+impl<T, A: Allocator> IterList<T, A> {
+	/// Create a new empty list, allocating its nodes in `alloc`. `O(1)`.  
+	/// Nodes are freed through the allocator of the list they end up in, and nothing proves two allocators
+	/// are the same instance, so trading nodes between lists (`append`, `splice_*`, `transfer_*`, `merge`, ...) is only available in `Global`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let bump = bumpalo::Bump::new();
+	/// let mut list = IterList::new_in(&bump);
+	/// list.push_next(1);
+	/// list.push_next(2);
+	/// assert!(bump.allocated_bytes() > 0);
+	///
+	/// let tail = list.split_before().unwrap();
+	/// assert_eq!(format!("{:?}", list), "[2]");
+	/// assert_eq!(format!("{:?}", tail), "[1]");
+	/// ```
+	#[inline]
+	pub const fn new_in(alloc: A) -> Self {
+		Self { 
+			current: NonNull::dangling(),
+			front:   NonNull::dangling(),
+			back:    NonNull::dangling(),
+			len:     0,
+			index:   0,
+			handles: None,
+			pool:    None,
+			id:      AtomicU64::new(0),
+			version: 0,
+			alloc,
+			_boo:    PhantomData
+		}
+	}
+
+
 	/// Insert an element after the cursor, retaining current position. `O(1)`.  
 	/// If the list is empty it will be inserted at index 0.
 	/// ```
@@ -88,24 +236,8 @@ impl<T> IterList<T> {
 	/// assert_eq!(&format!("{:?}", list), "[1, 3, 2]");
 	/// ```
 	pub fn insert_next(&mut self, elem: T) {
-		let mut new = Node::new_nonnull(elem);
-
-		match self.len {
-			0 => self.current = new,
-			_ => {
-				unsafe {
-					if let Some(mut next) = self.current.as_mut().next {
-						next.as_mut().prev = Some(new);
-						new.as_mut().next  = Some(next);
-					}
-
-					self.current.as_mut().next = Some(new);
-					new.as_mut().prev          = Some(self.current);
-				}
-			},
-		}
-
-		self.len += 1;
+		let new = self.alloc_node(elem);
+		self.link_next(new);
 	}
 
 	/// Insert an element before the cursor, retaining current position. `O(1)`.  
@@ -121,26 +253,8 @@ impl<T> IterList<T> {
 	/// assert_eq!(&format!("{:?}", list), "[2, 3, 1]");
 	/// ```
 	pub fn insert_prev(&mut self, elem: T) {
-		let mut new = Node::new_nonnull(elem);
-
-		match self.len {
-			0 => self.current = new,
-			_ => {
-				unsafe {
-					if let Some(mut prev) = self.current.as_mut().prev {
-						prev.as_mut().next = Some(new);
-						new.as_mut().prev  = Some(prev);
-					}
-
-					self.current.as_mut().prev = Some(new);
-					new.as_mut().next          = Some(self.current);
-				}
-
-				self.index += 1;
-			},
-		}
-
-		self.len += 1;
+		let new = self.alloc_node(elem);
+		self.link_prev(new);
 	}
 
 	/// Push an element after the cursor, moving the cursor to it. `O(1)`.  
@@ -189,8 +303,183 @@ impl<T> IterList<T> {
 		let _ = self.retreat();
 	}
 
-	/// Move the cursor to the front of the list. `O(n)`.  
-	/// Returns the number of elements traversed.
+	/// Same as `insert_next`, but hands the element back instead of aborting if the allocator fails. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let bump = bumpalo::Bump::new();
+	/// let mut list = IterList::new_in(&bump);
+	/// assert_eq!(list.try_insert_next(1), Ok(()));
+	///
+	/// bump.set_allocation_limit(Some(bump.allocated_bytes()));
+	/// let mut big = IterList::new_in(&bump);
+	/// let (elem, _) = big.try_insert_next([0u8; 4096]).unwrap_err();
+	/// assert_eq!(elem, [0; 4096]);
+	/// assert!(big.is_empty());
+	/// ```
+	pub fn try_insert_next(&mut self, elem: T) -> Result<(), (T, AllocError)> {
+		let new = self.try_alloc_node(elem)?;
+		self.link_next(new);
+		Ok(())
+	}
+
+	/// Same as `insert_prev`, but hands the element back instead of aborting if the allocator fails. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let bump = bumpalo::Bump::new();
+	/// bump.set_allocation_limit(Some(0));
+	///
+	/// let mut list = IterList::new_in(&bump);
+	/// assert_eq!(list.try_insert_prev("tok").map_err(|(elem, _)| elem), Err("tok"));
+	/// assert!(list.is_empty());
+	/// ```
+	pub fn try_insert_prev(&mut self, elem: T) -> Result<(), (T, AllocError)> {
+		let new = self.try_alloc_node(elem)?;
+		self.link_prev(new);
+		Ok(())
+	}
+
+	/// Same as `push_next`, but hands the element back instead of aborting if the allocator fails. `O(1)`.  
+	/// The cursor only moves if the element was inserted.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::new();
+	/// assert!(list.try_push_next(1).is_ok());
+	/// assert!(list.try_push_next(2).is_ok());
+	///
+	/// assert_eq!(list.current(), Some(&2));
+	/// assert_eq!(format!("{:?}", list), "[1, 2]");
+	/// ```
+	pub fn try_push_next(&mut self, elem: T) -> Result<(), (T, AllocError)> {
+		let was_empty = self.len == 0;
+		self.try_insert_next(elem)?;
+		if !was_empty { let _ = self.advance(); }
+		Ok(())
+	}
+
+	/// Same as `push_prev`, but hands the element back instead of aborting if the allocator fails. `O(1)`.  
+	/// The cursor only moves if the element was inserted.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::new();
+	/// assert!(list.try_push_prev(1).is_ok());
+	/// assert!(list.try_push_prev(2).is_ok());
+	///
+	/// assert_eq!(list.current(), Some(&2));
+	/// assert_eq!(format!("{:?}", list), "[2, 1]");
+	/// ```
+	pub fn try_push_prev(&mut self, elem: T) -> Result<(), (T, AllocError)> {
+		let was_empty = self.len == 0;
+		self.try_insert_prev(elem)?;
+		if !was_empty { let _ = self.retreat(); }
+		Ok(())
+	}
+
+	/// Push an element to the front of the list, leaving the cursor on its element. `O(1)`.  
+	/// If the list is empty it will be inserted at index 0.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![2, 3]);
+	/// list.advance();
+	/// list.push_front(1);
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+	/// assert_eq!(list.current(), Some(&3));
+	/// assert_eq!(list.index(), 2);
+	/// ```
+	pub fn push_front(&mut self, elem: T) {
+		let mut new = self.alloc_node(elem);
+		let fresh = self.labels_fresh();
+		self.version += 1;
+
+		match self.len {
+			0 => self.reset_to(new),
+			_ => {
+				unsafe {
+					new.as_mut().next        = Some(self.front);
+					self.front.as_mut().prev = Some(new);
+				}
+
+				self.front  = new;
+				self.index += 1;
+			},
+		}
+
+		self.len += 1;
+		self.label_linked(new, fresh);
+	}
+
+	/// Push an element to the back of the list, leaving the cursor on its element. `O(1)`.  
+	/// If the list is empty it will be inserted at index 0.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2]);
+	/// list.push_back(3);
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	pub fn push_back(&mut self, elem: T) {
+		let mut new = self.alloc_node(elem);
+		let fresh = self.labels_fresh();
+		self.version += 1;
+
+		match self.len {
+			0 => self.reset_to(new),
+			_ => {
+				unsafe {
+					new.as_mut().prev       = Some(self.back);
+					self.back.as_mut().next = Some(new);
+				}
+
+				self.back = new;
+			},
+		}
+
+		self.len += 1;
+		self.label_linked(new, fresh);
+	}
+
+	/// Remove the first element and return it. `O(1)`.  
+	/// The cursor stays on its element, unless it was the one removed,
+	/// in which case it moves to the new front, just like `consume_forward`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// list.move_to_back();
+	///
+	/// assert_eq!(list.pop_front(), Some(1));
+	/// assert_eq!(format!("{:?}", list), "[2, 3]");
+	/// assert_eq!(list.current(), Some(&3));
+	/// assert_eq!(list.index(), 1);
+	/// ```
+	pub fn pop_front(&mut self) -> Option<T> {
+		if self.len == 0 { return None; }
+		if self.current == self.front { return self.consume_forward().map(|(elem, _)| elem); }
+
+		self.index -= 1;
+		Some(unsafe { self.unlink(self.front) }.elem)
+	}
+
+	/// Remove the last element and return it. `O(1)`.  
+	/// The cursor stays on its element, unless it was the one removed,
+	/// in which case it moves to the new back, just like `consume_backward`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	///
+	/// assert_eq!(list.pop_back(), Some(3));
+	/// assert_eq!(format!("{:?}", list), "[1, 2]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	pub fn pop_back(&mut self) -> Option<T> {
+		if self.len == 0 { return None; }
+		if self.current == self.back { return self.consume_backward().map(|(elem, _)| elem); }
+
+		Some(unsafe { self.unlink(self.back) }.elem)
+	}
+
+	/// Move the cursor to the front of the list. `O(1)`.  
+	/// Returns the number of elements skipped over.
 	/// ```
 	/// # use iterlist::IterList;
 	/// let mut list = IterList::new();
@@ -205,21 +494,12 @@ impl<T> IterList<T> {
 	/// assert_eq!(list.current(), Some(&1));
 	/// ```
 	pub fn move_to_front(&mut self) -> usize {
-		if self.len == 0 { return 0; }
-
-		self.index = 0;
-
-		for i in 0_usize.. {
-			match unsafe { self.current.as_ref().prev } {
-				Some(prev) => self.current = prev,
-				None => return i,
-			}
-		}
-		unsafe { std::hint::unreachable_unchecked() }
+		self.current = self.front;
+		mem::take(&mut self.index)
 	}
 
-	/// Move the cursor to the back of the list. `O(n)`.  
-	/// Returns the number of elements traversed.
+	/// Move the cursor to the back of the list. `O(1)`.  
+	/// Returns the number of elements skipped over.
 	/// ```
 	/// # use iterlist::IterList;
 	/// let mut list = IterList::from(vec![1, 2, 3]);
@@ -231,18 +511,44 @@ impl<T> IterList<T> {
 	pub fn move_to_back(&mut self) -> usize {
 		if self.len == 0 { return 0; }
 
-		self.index = self.len - 1;
+		let skipped = self.len - 1 - self.index;
+		self.current = self.back;
+		self.index   = self.len - 1;
+		skipped
+	}
 
-		for i in 0_usize.. {
-			match unsafe { self.current.as_ref().next } {
-				Some(next) => self.current = next,
-				None => return i,
-			}
-		}
-		unsafe { std::hint::unreachable_unchecked() }
+	/// Check if the cursor is on the first element. `O(1)`.  
+	/// Returns `false` if the list is empty.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// assert!(list.is_at_front());
+	///
+	/// list.advance();
+	/// assert!(!list.is_at_front());
+	/// ```
+	#[inline]
+	pub fn is_at_front(&self) -> bool {
+		self.len != 0 && self.current == self.front
+	}
+
+	/// Check if the cursor is on the last element. `O(1)`.  
+	/// Returns `false` if the list is empty.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// assert!(!list.is_at_back());
+	///
+	/// list.move_to_back();
+	/// assert!(list.is_at_back());
+	/// ```
+	#[inline]
+	pub fn is_at_back(&self) -> bool {
+		self.len != 0 && self.current == self.back
 	}
 
 	/// Move the cursor to the specified index. `O(n)`.
+	/// Walks from whichever of the front, the back or the cursor is closest.  
 	/// If the index is out of bounds the cursor will be moved to the edge, 
 	/// and `false` will be returned.
 	/// ```
@@ -250,14 +556,23 @@ impl<T> IterList<T> {
 	/// let mut list = IterList::from(vec![1, 2, 3]);
 	/// list.move_to(1);
 	/// assert_eq!(list.current(), Some(&2));
+	///
+	/// assert!(!list.move_to(5));
+	/// assert_eq!(list.current(), Some(&3));
 	/// ```
 	#[inline]
 	#[must_use]
 	pub fn move_to(&mut self, index: usize) -> bool {
-		match self.index.cmp(&index) {
-			Ordering::Greater => !(0..self.index - index).any(|_| !self.retreat()),
-			Ordering::Less    => !(0..index - self.index).any(|_| !self.advance()),
-			Ordering::Equal   => true,
+		match self.node_at(index, Some((self.index, self.current))) {
+			Some(node) => {
+				self.current = node;
+				self.index   = index;
+				true
+			},
+			None => {
+				self.move_to_back();
+				self.is_empty() && index == 0
+			},
 		}
 	}
 
@@ -354,16 +669,41 @@ impl<T> IterList<T> {
 		}
 	}
 
-	fn get_raw(&self, offset: isize) -> Option<NonNull<Node<T>>> {
-		if self.index as isize + offset < 0 || self.index as isize + offset >= self.len as isize {
-			return None;
-		}
+	/// Find the node at an absolute index. `O(n)`.  
+	/// Walks from whichever of the front, the back or `from` (an index and its node) is closest.
+	fn node_at(&self, index: usize, from: Option<(usize, NonNull<Node<T>>)>) -> Option<NonNull<Node<T>>> {
+		if index >= self.len { return None; }
 
-		match offset.cmp(&0) {
-			Ordering::Greater => (0.. offset).try_fold(self.current, |mut ptr, _| unsafe { ptr.as_ref() }.next.map(|c| { ptr = c; ptr })),
-			Ordering::Less    => (0..-offset).try_fold(self.current, |mut ptr, _| unsafe { ptr.as_ref() }.prev.map(|c| { ptr = c; ptr })),
-			Ordering::Equal   => Some(self.current)
+		[(0, self.front), (self.len - 1, self.back)].into_iter()
+			.chain(from)
+			.min_by_key(|(i, _)| i.abs_diff(index))
+			.map(|(i, node)| unsafe { Node::walk(node, index as isize - i as isize) })
+	}
+
+	/// Find the index of a node of this list. `O(n)`.  
+	/// Walks out from the node in both directions until it meets the cursor, the front or the back.
+	fn index_of(&self, node: NonNull<Node<T>>) -> usize {
+		if node == self.current { return self.index; }
+
+		let (mut next, mut prev) = (node, node);
+		for steps in 1.. {
+			match unsafe { next.as_ref() }.next {
+				None => return self.len - steps,
+				Some(n) if n == self.current => return self.index - steps,
+				Some(n) => next = n,
+			}
+			match unsafe { prev.as_ref() }.prev {
+				None => return steps - 1,
+				Some(p) if p == self.current => return self.index + steps,
+				Some(p) => prev = p,
+			}
 		}
+		unreachable!()
+	}
+
+	fn get_raw(&self, offset: isize) -> Option<NonNull<Node<T>>> {
+		self.index.checked_add_signed(offset)
+			.and_then(|index| self.node_at(index, Some((self.index, self.current))))
 	}
 
 	/// Get a ref to an element at the given offset from the cursor. `O(n)`.
@@ -394,6 +734,38 @@ impl<T> IterList<T> {
 		self.get_raw(offset).map(|mut ptr| unsafe { &mut ptr.as_mut().elem })
 	}
 
+	/// Get a ref to the element at an absolute index. `O(n)`.  
+	/// Walks from whichever of the front, the back or the cursor is closest.
+	/// Returns `None` if the index is out of bounds.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3, 4]);
+	/// list.move_to_back();
+	///
+	/// assert_eq!(list.nth_abs(0), Some(&1));
+	/// assert_eq!(list.nth_abs(2), Some(&3));
+	/// assert_eq!(list.nth_abs(4), None);
+	/// ```
+	#[inline]
+	pub fn nth_abs(&self, index: usize) -> Option<&T> {
+		self.node_at(index, Some((self.index, self.current))).map(|ptr| unsafe { &ptr.as_ref().elem })
+	}
+
+	/// Get a mut ref to the element at an absolute index. `O(n)`.  
+	/// Walks from whichever of the front, the back or the cursor is closest.
+	/// Returns `None` if the index is out of bounds.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	///
+	/// *list.nth_abs_mut(2).unwrap() = 4;
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 4]");
+	/// ```
+	#[inline]
+	pub fn nth_abs_mut(&mut self, index: usize) -> Option<&mut T> {
+		self.node_at(index, Some((self.index, self.current))).map(|mut ptr| unsafe { &mut ptr.as_mut().elem })
+	}
+
 	/// Remove the current element and return it. `O(1)`.  
 	/// The cursor will then point to the next element.  
 	/// If the removed element was at the end of the list, the cursor will point to the previous
@@ -408,159 +780,1335 @@ impl<T> IterList<T> {
 	pub fn consume_forward(&mut self) -> Option<(T, bool)> {
 		if self.len == 0 { return None; }
 
-		let node = unsafe { Box::from_raw(self.current.as_ptr()) };
-
-		self.len -= 1;
-		node.prev.map(|mut prev| unsafe { prev.as_mut().next = node.next });
+		let node = unsafe { self.unlink(self.current) };
 
 		match node.next {
-			Some(mut next) => {
-				unsafe { next.as_mut().prev = node.prev; }
+			Some(next) => {
 				self.current = next;
 				Some((node.elem, true))
 			},
 			None => {
-				self.current = node.prev.unwrap_or(NonNull::dangling()); // FIXME: idkk if this works
+				self.current = self.back;
+				self.index   = self.index.saturating_sub(1);
 				Some((node.elem, false))
 			}
 		}
 	}
 
-	/// Remove the current element and return it. `O(1)`.  
-	/// The cursor will then point to the previous element.  
-	/// If the removed element was at the end of the list, the cursor will point to the previous
-	/// element and `false` will be returned.
+	/// Remove the current element and return it. `O(1)`.  
+	/// The cursor will then point to the previous element.  
+	/// If the removed element was at the end of the list, the cursor will point to the previous
+	/// element and `false` will be returned.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// list.move_by(1);
+	/// assert_eq!(list.consume_backward(), Some((2, true)));
+	/// assert_eq!(&format!("{:?}", list), "[1, 3]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	pub fn consume_backward(&mut self) -> Option<(T, bool)> {
+		if self.len == 0 { return None; }
+
+		let node = unsafe { self.unlink(self.current) };
+
+		match node.prev {
+			Some(prev) => {
+				self.current = prev;
+				self.index  -= 1;
+				Some((node.elem, true))
+			},
+			None => {
+				self.current = self.front;
+				Some((node.elem, false))
+			}
+		}
+	}
+
+	/// Replace the current element with a new one. `O(1)`.  
+	/// Returns the old element.  
+	/// If the list is empty, the new element will be inserted, and `None` returned.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// assert_eq!(list.replace_cursor(4), Some(1));
+	/// assert_eq!(format!("{:?}", list), "[4, 2, 3]");
+	/// ```
+	#[inline]
+	pub fn replace_cursor(&mut self, elem: T) -> Option<T> {
+		match self.len {
+			0 => { self.push_next(elem); None },
+			_ => Some(std::mem::replace(unsafe { &mut self.current.as_mut().elem }, elem)),
+		}
+	}
+
+	/// Apply the current state of a cursor to the list. `O(1)`.
+	///
+	/// # Safety
+	/// The cursor must be valid and point to a `Node` in the same list.
+	#[inline]
+	pub unsafe fn apply_cursor_unchecked(&mut self, cursor: &Cursor<T, A>) {
+		self.current = cursor.current.unwrap_unchecked();
+		self.index   = cursor.index;
+	}
+
+	/// Move the list's cursor to where `cursor` is. `O(1)`.  
+	/// The cursor has to come from this list, which must not have been modified
+	/// (elements inserted, removed, split off or spliced in) since the cursor was taken.
+	/// Otherwise the list is left alone and the reason is returned.
+	/// ```
+	/// # use iterlist::{IterList, CursorError};
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// let mut cursor = list.as_cursor();
+	/// cursor.move_to_back();
+	/// let cursor = cursor.detach();
+	///
+	/// assert_eq!(list.apply_cursor(&cursor), Ok(()));
+	/// assert_eq!(list.current(), Some(&3));
+	///
+	/// list.push_front(0);
+	/// assert_eq!(list.apply_cursor(&cursor), Err(CursorError::Stale));
+	///
+	/// let other = IterList::from(vec![1, 2, 3]);
+	/// assert_eq!(list.apply_cursor(&other.as_cursor()), Err(CursorError::Foreign));
+	/// ```
+	pub fn apply_cursor(&mut self, cursor: &Cursor<T, A>) -> Result<(), CursorError> {
+		if let Some(current) = self.check_cursor(cursor)? {
+			self.current = current;
+			self.index   = cursor.index;
+		}
+		Ok(())
+	}
+
+	/// Make sure a cursor was taken from this list, and nothing changed since. `O(1)`.
+	fn check_cursor(&mut self, cursor: &Cursor<T, A>) -> Result<Option<NonNull<Node<T>>>, CursorError> {
+		match cursor.id {
+			0 => Err(CursorError::Unchecked),
+			id if id != *self.id.get_mut() => Err(CursorError::Foreign),
+			_ if cursor.version != self.version => Err(CursorError::Stale),
+			_ => Ok(cursor.current),
+		}
+	}
+
+	/// Split the list after the cursor. `O(1)`,
+	/// plus the length of the split off part if any handles were given out.  
+	/// If the list is empty, or the cursor is at the end, `None` will be returned.  
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// list.advance();
+	/// let new_list = list.split_after().unwrap();
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2]");
+	/// assert_eq!(format!("{:?}", new_list), "[3]");
+	/// assert_eq!(new_list.index(), 0);
+	/// ```
+	pub fn split_after(&mut self) -> Option<Self> where A: Clone {
+		if self.len == 0 { return None; }
+
+		unsafe { self.current.as_ref() }.next.map(|next| {
+			let mut new = self.sibling();
+			new.current = next;
+			new.front   = next;
+			new.back    = mem::replace(&mut self.back, self.current);
+
+			new.len = self.len - self.index - 1;
+			self.len -= new.len;
+			self.forget_nodes(next, new.len);
+			self.version += 1;
+
+			unsafe { self.current.as_mut().next = None; }
+			unsafe { new .current.as_mut().prev = None; }
+
+			new
+		})
+	}
+
+	/// Split the list before the cursor. `O(1)`,
+	/// plus the length of the split off part if any handles were given out.  
+	/// If the list is empty, or the cursor is at the front, `None` will be returned.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3, 4]);
+	/// list.move_by(2);
+	/// let new_list = list.split_before().unwrap();
+	///
+	/// assert_eq!(format!("{:?}", list), "[3, 4]");
+	/// assert_eq!(format!("{:?}", new_list), "[1, 2]");
+	/// assert_eq!(new_list.index(), 1);
+	/// assert_eq!(list.index(), 0);
+	/// ```
+	pub fn split_before(&mut self) -> Option<Self> where A: Clone {
+		if self.len == 0 { return None; }
+
+		unsafe { self.current.as_ref() }.prev.map(|prev| {
+			let mut new = self.sibling();
+
+			new.current = prev;
+			new.back    = prev;
+			new.front   = mem::replace(&mut self.front, self.current);
+			new.len = self.index;
+			self.len -= new.len;
+			self.index = 0;
+			new.index = new.len - 1;
+			self.forget_nodes(new.front, new.len);
+			self.version += 1;
+
+			unsafe { self.current.as_mut().prev = None; }
+			unsafe { new .current.as_mut().next = None; }
+
+			new
+		})
+	}
+}
+
+// Lists only trade nodes in `Global`, nothing guarantees two instances of another allocator can free each other's memory.
+impl<T> IterList<T> {
+	/// Append another list to the back of this one. `O(1)`.
+	/// Only the boundary nodes are relinked, no elements are moved or reallocated.
+	/// The cursor is left where it was, if this list was empty it takes over the cursor of `other`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// list.advance();
+	/// list.append(IterList::from(vec![4, 5]));
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3, 4, 5]");
+	/// assert_eq!(list.current(), Some(&2));
+	/// assert_eq!(list.index(), 1);
+	/// assert_eq!(list.len(), 5);
+	/// ```
+	pub fn append(&mut self, mut other: Self) {
+		if other.is_empty() { return; }
+		if self.is_empty() { return self.take_over(other); }
+
+		unsafe {
+			self.back.as_mut().next  = Some(other.front);
+			other.front.as_mut().prev = Some(self.back);
+		}
+
+		self.back = other.back;
+		self.len += other.len;
+		self.version += 1;
+		other.release();
+	}
+
+	/// Prepend another list to the front of this one. `O(1)`.
+	/// Only the boundary nodes are relinked, no elements are moved or reallocated.
+	/// The cursor stays on the same element, its index is shifted by the length of `other`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![3, 4]);
+	/// list.prepend(IterList::from(vec![1, 2]));
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3, 4]");
+	/// assert_eq!(list.current(), Some(&3));
+	/// assert_eq!(list.index(), 2);
+	/// ```
+	pub fn prepend(&mut self, mut other: Self) {
+		if other.is_empty() { return; }
+		if self.is_empty() { return self.take_over(other); }
+
+		unsafe {
+			other.back.as_mut().next = Some(self.front);
+			self.front.as_mut().prev = Some(other.back);
+		}
+
+		self.front  = other.front;
+		self.len   += other.len;
+		self.index += other.len;
+		self.version += 1;
+		other.release();
+	}
+
+	/// Splice another list in right after the cursor. `O(1)`.
+	/// The counterpart of `split_after`, nodes are relinked without reallocating.
+	/// `cursor` decides where the cursor lands, if this list was empty `Splice::Stay` keeps the cursor of `other`.
+	/// ```
+	/// # use iterlist::{IterList, Splice};
+	/// let mut list = IterList::from(vec![1, 4]);
+	/// list.splice_after(IterList::from(vec![2, 3]), Splice::Stay);
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3, 4]");
+	/// assert_eq!(list.current(), Some(&1));
+	///
+	/// list.splice_after(IterList::from(vec![5, 6]), Splice::Last);
+	/// assert_eq!(format!("{:?}", list), "[1, 5, 6, 2, 3, 4]");
+	/// assert_eq!(list.current(), Some(&6));
+	/// assert_eq!(list.index(), 2);
+	/// ```
+	pub fn splice_after(&mut self, other: Self, cursor: Splice) {
+		if other.is_empty() { return; }
+		if self.is_empty() { return self.take_spliced(other, cursor); }
+
+		let (mut front, mut back) = (other.front, other.back);
+
+		unsafe {
+			match self.current.as_ref().next {
+				Some(mut next) => {
+					next.as_mut().prev = Some(back);
+					back.as_mut().next = Some(next);
+				},
+				None => self.back = back,
+			}
+
+			self.current.as_mut().next = Some(front);
+			front.as_mut().prev        = Some(self.current);
+		}
+
+		self.len += other.len;
+		self.version += 1;
+
+		match cursor {
+			Splice::Stay  => (),
+			Splice::First => { self.current = front; self.index += 1; },
+			Splice::Last  => { self.current = back;  self.index += other.len; },
+		}
+
+		other.release();
+	}
+
+	/// Splice another list in right before the cursor. `O(1)`.
+	/// The counterpart of `split_before`, nodes are relinked without reallocating.
+	/// `cursor` decides where the cursor lands, if this list was empty `Splice::Stay` keeps the cursor of `other`.
+	/// ```
+	/// # use iterlist::{IterList, Splice};
+	/// let mut list = IterList::from(vec![1, 4]);
+	/// list.advance();
+	/// list.splice_before(IterList::from(vec![2, 3]), Splice::Stay);
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3, 4]");
+	/// assert_eq!(list.current(), Some(&4));
+	/// assert_eq!(list.index(), 3);
+	///
+	/// list.splice_before(IterList::from(vec![5, 6]), Splice::First);
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3, 5, 6, 4]");
+	/// assert_eq!(list.current(), Some(&5));
+	/// assert_eq!(list.index(), 3);
+	/// ```
+	pub fn splice_before(&mut self, other: Self, cursor: Splice) {
+		if other.is_empty() { return; }
+		if self.is_empty() { return self.take_spliced(other, cursor); }
+
+		let (mut front, mut back) = (other.front, other.back);
+
+		unsafe {
+			match self.current.as_ref().prev {
+				Some(mut prev) => {
+					prev.as_mut().next  = Some(front);
+					front.as_mut().prev = Some(prev);
+				},
+				None => self.front = front,
+			}
+
+			self.current.as_mut().prev = Some(back);
+			back.as_mut().next         = Some(self.current);
+		}
+
+		self.len += other.len;
+		self.version += 1;
+
+		match cursor {
+			Splice::Stay  => self.index += other.len,
+			Splice::First => self.current = front,
+			Splice::Last  => { self.current = back; self.index += other.len - 1; },
+		}
+
+		other.release();
+	}
+
+	/// Move the current element over to `dest`, next to its cursor. `O(1)`.  
+	/// The node is relinked, not reallocated. This list's cursor moves on like in `consume_forward`,
+	/// the cursor of `dest` stays on its element, or lands on the moved one if `dest` was empty.
+	/// Returns `false` if this list is empty.
+	/// ```
+	/// # use iterlist::{IterList, Side};
+	/// let mut pending = IterList::from(vec![1, 2, 3]);
+	/// let mut done = IterList::from(vec![4, 5]);
+	/// pending.advance();
+	///
+	/// assert!(pending.transfer_current_to(&mut done, Side::Next));
+	/// assert_eq!(format!("{:?}", pending), "[1, 3]");
+	/// assert_eq!(pending.current(), Some(&3));
+	/// assert_eq!(format!("{:?}", done), "[4, 2, 5]");
+	/// assert_eq!(done.current(), Some(&4));
+	///
+	/// assert!(pending.transfer_current_to(&mut done, Side::Prev));
+	/// assert_eq!(format!("{:?}", done), "[3, 4, 2, 5]");
+	/// assert_eq!(done.index(), 1);
+	/// ```
+	pub fn transfer_current_to(&mut self, dest: &mut Self, side: Side) -> bool {
+		if self.len == 0 { return false; }
+
+		let (current, index) = (self.current, self.index);
+		let node = unsafe { self.unlink_nodes(current, current, index, index + 1) };
+		dest.splice_side(node, side);
+		true
+	}
+
+	/// Move the elements between two cursors over to `dest`, next to its cursor. `O(1)`,
+	/// plus the length of the range if any handles were given out.  
+	/// The range includes the elements under both cursors, whichever order they're in.
+	/// The nodes are relinked, not reallocated, and the cursors move as in `drain` and `transfer_current_to`.  
+	/// Both cursors are checked the same way as in `apply_cursor`, so they have to be detached cursors of this list.
+	/// ```
+	/// # use iterlist::{IterList, Side};
+	/// let mut list = IterList::from(vec![1, 2, 3, 4, 5]);
+	/// let mut dest = IterList::from(vec![6, 7]);
+	/// dest.advance();
+	///
+	/// let mut from = list.as_cursor();
+	/// from.advance();
+	/// let mut to = from;
+	/// to.move_by(2);
+	/// let (from, to) = (from.detach(), to.detach());
+	///
+	/// assert!(list.transfer_range_to(&from, &to, &mut dest, Side::Prev).is_ok());
+	/// assert_eq!(format!("{:?}", list), "[1, 5]");
+	/// assert_eq!(format!("{:?}", dest), "[6, 2, 3, 4, 7]");
+	/// assert_eq!(dest.current(), Some(&7));
+	/// assert_eq!(dest.index(), 4);
+	///
+	/// // the list has changed since the cursors were taken
+	/// assert!(list.transfer_range_to(&from, &to, &mut dest, Side::Next).is_err());
+	/// ```
+	pub fn transfer_range_to(&mut self, from: &Cursor<T>, to: &Cursor<T>, dest: &mut Self, side: Side) -> Result<(), CursorError> {
+		let (Some(first), Some(last)) = (self.check_cursor(from)?, self.check_cursor(to)?) else { return Ok(()) };
+		let ((first, start), (last, end)) = if from.index <= to.index {
+			((first, from.index), (last, to.index))
+		} else {
+			((last, to.index), (first, from.index))
+		};
+
+		let nodes = unsafe { self.unlink_nodes(first, last, start, end + 1) };
+		dest.splice_side(nodes, side);
+		Ok(())
+	}
+
+	/// Splice a list in on the given side of the cursor, leaving the cursor alone. `O(1)`.
+	#[inline]
+	fn splice_side(&mut self, other: Self, side: Side) {
+		match side {
+			Side::Next => self.splice_after(other, Splice::Stay),
+			Side::Prev => self.splice_before(other, Splice::Stay),
+		}
+	}
+}
+
+impl<T, A: Allocator> IterList<T, A> {
+	/// Remove a range of elements, by absolute index, and iterate over them. `O(n)`.  
+	/// The range is unlinked right away, so the list stays consistent even if the iterator is leaked,
+	/// and any elements that aren't yielded are dropped with it.  
+	/// If the cursor was inside the range it lands on the element right after it,
+	/// or on the new back if the range reached the end. Otherwise it stays on the same element.
+	///
+	/// # Panics
+	/// Panics if the range is out of bounds, or its start comes after its end.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3, 4, 5]);
+	/// list.move_to(2);
+	///
+	/// assert_eq!(list.drain(1..3).collect::<Vec<_>>(), [2, 3]);
+	/// assert_eq!(format!("{:?}", list), "[1, 4, 5]");
+	/// assert_eq!(list.current(), Some(&4));
+	/// assert_eq!(list.index(), 1);
+	/// ```
+	pub fn drain(&mut self, range: impl RangeBounds<usize>) -> Drain<'_, T, A> where A: Clone {
+		let start = match range.start_bound() {
+			Bound::Included(&i) => i,
+			Bound::Excluded(&i) => i.checked_add(1).expect("drain range out of bounds"),
+			Bound::Unbounded    => 0,
+		};
+		let end = match range.end_bound() {
+			Bound::Included(&i) => i.checked_add(1).expect("drain range out of bounds"),
+			Bound::Excluded(&i) => i,
+			Bound::Unbounded    => self.len,
+		};
+		assert!(start <= end && end <= self.len, "drain range {start}..{end} out of bounds for length {}", self.len);
+
+		Drain::new(self.unlink_range(start, end))
+	}
+
+	/// Remove a range of elements, by offset from the cursor, and iterate over them. `O(n)`.  
+	/// Works just like `drain`, `0` being the current element.
+	///
+	/// # Panics
+	/// Panics if the range is out of bounds, or its start comes after its end.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3, 4, 5]);
+	/// list.move_to(2);
+	///
+	/// assert_eq!(list.drain_relative(-1..=1).collect::<Vec<_>>(), [2, 3, 4]);
+	/// assert_eq!(format!("{:?}", list), "[1, 5]");
+	/// assert_eq!(list.current(), Some(&5));
+	///
+	/// list.drain_relative(..);
+	/// assert!(list.is_empty());
+	/// ```
+	pub fn drain_relative(&mut self, range: impl RangeBounds<isize>) -> Drain<'_, T, A> where A: Clone {
+		let index = self.index;
+		let abs = |bound: Bound<&isize>| bound
+			.map(|&offset| index.checked_add_signed(offset).expect("drain range out of bounds"));
+
+		self.drain((abs(range.start_bound()), abs(range.end_bound())))
+	}
+
+	/// Keep only the elements for which `f` returns `true`, visiting them front to back. `O(n)`.  
+	/// The cursor stays on the same element if it's kept. If it's removed,
+	/// the cursor moves on to the next kept element, or the last one if there is none after it.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3, 4, 5, 6]);
+	/// list.move_to(3);
+	///
+	/// list.retain(|&elem| elem % 2 == 1);
+	/// assert_eq!(format!("{:?}", list), "[1, 3, 5]");
+	/// assert_eq!(list.current(), Some(&5));
+	/// assert_eq!(list.index(), 2);
+	/// ```
+	#[inline]
+	pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+		self.retain_mut(|elem| f(elem));
+	}
+
+	/// Same as `retain`, but `f` gets a mut ref to each element. `O(n)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3, 4]);
+	///
+	/// list.retain_mut(|elem| { *elem *= 10; *elem != 20 });
+	/// assert_eq!(format!("{:?}", list), "[10, 30, 40]");
+	/// ```
+	#[inline]
+	pub fn retain_mut(&mut self, mut f: impl FnMut(&mut T) -> bool) {
+		self.extract_if(|elem| !f(elem)).for_each(mem::drop);
+	}
+
+	/// Lazily remove the elements for which `pred` returns `true`, yielding them front to back.  
+	/// Each call to `next` is `O(k)`, where `k` is the number of elements looked at.
+	/// Dropping the iterator early keeps the elements it hasn't reached yet.  
+	/// The cursor is moved the same way as in `retain`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3, 4, 5]);
+	/// list.move_to_back();
+	///
+	/// let mut evens = list.extract_if(|elem| *elem % 2 == 0);
+	/// assert_eq!(evens.next(), Some(2));
+	/// drop(evens);
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 3, 4, 5]");
+	/// assert_eq!(list.current(), Some(&5));
+	/// assert_eq!(list.index(), 3);
+	/// ```
+	#[inline]
+	pub fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, pred: F) -> ExtractIf<'_, T, F, A> {
+		ExtractIf::new(self, pred)
+	}
+
+	/// Reverse the order of the list in place. `O(n)`.  
+	/// Every node just swaps its links, nothing is moved or reallocated.
+	/// The cursor stays on the same element, so its index becomes `len - 1 - index`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3, 4]);
+	/// list.advance();
+	///
+	/// list.reverse();
+	/// assert_eq!(format!("{:?}", list), "[4, 3, 2, 1]");
+	/// assert_eq!(list.current(), Some(&2));
+	/// assert_eq!(list.index(), 2);
+	/// ```
+	pub fn reverse(&mut self) {
+		if self.len < 2 { return; }
+
+		let mut node = Some(self.front);
+		while let Some(mut n) = node {
+			let n = unsafe { n.as_mut() };
+			mem::swap(&mut n.next, &mut n.prev);
+			node = n.prev;
+		}
+
+		mem::swap(&mut self.front, &mut self.back);
+		self.index = self.len - 1 - self.index;
+		self.version += 1;
+	}
+
+	/// Rotate the list `k` places to the left, so the element at index `k` becomes the front. `O(n)`.  
+	/// Only the ends are relinked, the cost is finding the new front,
+	/// which walks from the nearest of the front, the back or the cursor.
+	/// The cursor stays on the same element.
+	///
+	/// # Panics
+	/// Panics if `k` is greater than the length of the list.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3, 4, 5]);
+	///
+	/// list.rotate_left(2);
+	/// assert_eq!(format!("{:?}", list), "[3, 4, 5, 1, 2]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// assert_eq!(list.index(), 3);
+	/// ```
+	pub fn rotate_left(&mut self, k: usize) {
+		assert!(k <= self.len, "rotate by {k} out of bounds for length {}", self.len);
+		if k == 0 || k == self.len { return; }
+
+		let mut front = unsafe { self.node_at(k, Some((self.index, self.current))).unwrap_unchecked() };
+
+		unsafe {
+			self.back.as_mut().next  = Some(self.front);
+			self.front.as_mut().prev = Some(self.back);
+
+			self.back = front.as_ref().prev.unwrap_unchecked();
+			self.back.as_mut().next = None;
+			front.as_mut().prev     = None;
+		}
+
+		self.front = front;
+		self.index = (self.index + self.len - k) % self.len;
+		self.version += 1;
+	}
+
+	/// Rotate the list `k` places to the right, so the element at index `len - k` becomes the front. `O(n)`.  
+	/// Same as `rotate_left(len - k)`.
+	///
+	/// # Panics
+	/// Panics if `k` is greater than the length of the list.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3, 4, 5]);
+	///
+	/// list.rotate_right(2);
+	/// assert_eq!(format!("{:?}", list), "[4, 5, 1, 2, 3]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// assert_eq!(list.index(), 2);
+	/// ```
+	#[inline]
+	pub fn rotate_right(&mut self, k: usize) {
+		assert!(k <= self.len, "rotate by {k} out of bounds for length {}", self.len);
+		self.rotate_left(self.len - k);
+	}
+
+	/// Move the current element to the front of the list. `O(1)`.  
+	/// The node is relinked, not reallocated, and the cursor stays on it.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// list.move_to_back();
+	///
+	/// list.move_current_to_front();
+	/// assert_eq!(format!("{:?}", list), "[3, 1, 2]");
+	/// assert_eq!(list.current(), Some(&3));
+	/// assert_eq!(list.index(), 0);
+	/// ```
+	pub fn move_current_to_front(&mut self) {
+		if self.index == 0 { return; }
+
+		unsafe {
+			self.detach(self.current);
+			self.link_before(self.current, self.front);
+		}
+		self.index = 0;
+	}
+
+	/// Move the current element to the back of the list. `O(1)`.  
+	/// The node is relinked, not reallocated, and the cursor stays on it.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	///
+	/// list.move_current_to_back();
+	/// assert_eq!(format!("{:?}", list), "[2, 3, 1]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// assert_eq!(list.index(), 2);
+	/// ```
+	pub fn move_current_to_back(&mut self) {
+		if self.len == 0 || self.index == self.len - 1 { return; }
+
+		unsafe {
+			self.detach(self.current);
+			self.link_after(self.current, self.back);
+		}
+		self.index = self.len - 1;
+	}
+
+	/// Swap the current element with the next one, by relinking their nodes. `O(1)`.  
+	/// The cursor moves along with its element. Returns `false` if there is no next element.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	///
+	/// assert!(list.swap_next());
+	/// assert_eq!(format!("{:?}", list), "[2, 1, 3]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// assert_eq!(list.index(), 1);
+	/// ```
+	pub fn swap_next(&mut self) -> bool {
+		if self.len == 0 { return false; }
+
+		unsafe { self.current.as_ref() }.next.map(|next| unsafe {
+			self.detach(next);
+			self.link_before(next, self.current);
+			self.index += 1;
+		}).is_some()
+	}
+
+	/// Swap the current element with the previous one, by relinking their nodes. `O(1)`.  
+	/// The cursor moves along with its element. Returns `false` if there is no previous element.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// list.move_to_back();
+	///
+	/// assert!(list.swap_prev());
+	/// assert_eq!(format!("{:?}", list), "[1, 3, 2]");
+	/// assert_eq!(list.current(), Some(&3));
+	/// assert_eq!(list.index(), 1);
+	/// ```
+	pub fn swap_prev(&mut self) -> bool {
+		if self.len == 0 { return false; }
+
+		unsafe { self.current.as_ref() }.prev.map(|prev| unsafe {
+			self.detach(prev);
+			self.link_after(prev, self.current);
+			self.index -= 1;
+		}).is_some()
+	}
+
+	/// Swap the current element with the one under `other`, by relinking their nodes. `O(1)`.  
+	/// The cursor moves along with its element, to the index of `other`.
+	/// `other` is checked the same way as in `apply_cursor`, so it has to be a detached cursor of this list.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3, 4]);
+	/// let mut other = list.as_cursor();
+	/// other.move_to_back();
+	/// let other = other.detach();
+	///
+	/// assert!(list.swap_with(&other).is_ok());
+	/// assert_eq!(format!("{:?}", list), "[4, 2, 3, 1]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// assert_eq!(list.index(), 3);
+	///
+	/// // the list has changed since `other` was taken
+	/// assert!(list.swap_with(&other).is_err());
+	/// ```
+	pub fn swap_with(&mut self, other: &Cursor<T, A>) -> Result<(), CursorError> {
+		let Some(node) = self.check_cursor(other)? else { return Ok(()) };
+		let current = self.current;
+		if node == current { return Ok(()); }
+
+		unsafe {
+			let (prev, next) = (current.as_ref().prev, current.as_ref().next);
+
+			if next == Some(node) {
+				self.detach(node);
+				self.link_before(node, current);
+			} else if prev == Some(node) {
+				self.detach(node);
+				self.link_after(node, current);
+			} else {
+				self.detach(current);
+				self.link_before(current, node);
+				self.detach(node);
+				match next {
+					Some(next) => self.link_before(node, next),
+					None => self.link_after(node, prev.unwrap_unchecked()),
+				}
+			}
+		}
+
+		self.index = other.index;
+		Ok(())
+	}
+
+	/// Move the current element `offset` places away, by relinking its node. `O(n)`.  
+	/// The cursor moves along with its element.
+	/// If the offset is out of bounds the element will be moved to the edge,
+	/// and `false` will be returned.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3, 4]);
+	///
+	/// assert!(list.move_current_by(2));
+	/// assert_eq!(format!("{:?}", list), "[2, 3, 1, 4]");
+	/// assert_eq!(list.index(), 2);
+	///
+	/// assert!(!list.move_current_by(-5));
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3, 4]");
+	/// assert_eq!(list.index(), 0);
+	/// ```
+	pub fn move_current_by(&mut self, offset: isize) -> bool {
+		if self.len == 0 { return false; }
+
+		let target  = self.index.checked_add_signed(offset).filter(|&i| i < self.len);
+		let clamped = target.unwrap_or(if offset < 0 { 0 } else { self.len - 1 });
+		if clamped == self.index { return target.is_some(); }
+
+		unsafe {
+			let at = self.node_at(clamped, Some((self.index, self.current))).unwrap_unchecked();
+			self.detach(self.current);
+
+			if clamped > self.index {
+				self.link_after(self.current, at);
+			} else {
+				self.link_before(self.current, at);
+			}
+		}
+
+		self.index = clamped;
+		target.is_some()
+	}
+
+	/// Remove consecutive repeated elements, keeping the first of each run. `O(n)`.  
+	/// If the cursor's element is removed, the cursor moves back onto the element it repeated.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 1, 2, 2, 2, 1]);
+	/// list.move_to(3);
+	///
+	/// list.dedup();
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 1]");
+	/// assert_eq!(list.current(), Some(&2));
+	/// assert_eq!(list.index(), 1);
+	/// ```
+	#[inline]
+	pub fn dedup(&mut self) where T: PartialEq {
+		self.dedup_by(|a, b| a == b);
+	}
+
+	/// Remove consecutive elements that map to the same key. `O(n)`.  
+	/// Moves the cursor the same way as `dedup`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![10, 11, 20, 21, 22, 30]);
+	///
+	/// list.dedup_by_key(|elem| *elem / 10);
+	/// assert_eq!(format!("{:?}", list), "[10, 20, 30]");
+	/// ```
+	#[inline]
+	pub fn dedup_by_key<K: PartialEq>(&mut self, mut key: impl FnMut(&mut T) -> K) {
+		self.dedup_by(|a, b| key(a) == key(b));
+	}
+
+	/// Remove consecutive elements for which `same_bucket` returns `true`. `O(n)`.  
+	/// `same_bucket` gets the element in question first, and the last element that was kept second.
+	/// Moves the cursor the same way as `dedup`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec!["a", "A", "b", "B", "c"]);
+	///
+	/// list.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+	/// assert_eq!(format!("{:?}", list), r#"["a", "b", "c"]"#);
+	/// ```
+	#[inline]
+	pub fn dedup_by(&mut self, same_bucket: impl FnMut(&mut T, &mut T) -> bool) {
+		self.dedup_by_merge(same_bucket, |_, _| ());
+	}
+
+	/// Same as `dedup_by`, but every removed element is handed to `merge` by value,
+	/// along with a mut ref to the kept element it repeated. `O(n)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// // (token, span length)
+	/// let mut list = IterList::from(vec![('a', 1), ('a', 2), ('b', 1), ('a', 1), ('a', 1)]);
+	///
+	/// list.dedup_by_merge(|a, b| a.0 == b.0, |kept, removed| kept.1 += removed.1);
+	/// assert_eq!(format!("{:?}", list), "[('a', 3), ('b', 1), ('a', 2)]");
+	/// ```
+	pub fn dedup_by_merge(
+		&mut self,
+		mut same_bucket: impl FnMut(&mut T, &mut T) -> bool,
+		mut merge: impl FnMut(&mut T, T),
+	) {
+		if self.len < 2 { return; }
+
+		let mut kept = self.front;
+		let mut pos  = 0;
+
+		while let Some(mut node) = unsafe { kept.as_ref() }.next {
+			if !unsafe { same_bucket(&mut node.as_mut().elem, &mut kept.as_mut().elem) } {
+				kept = node;
+				pos += 1;
+				continue;
+			}
+
+			let elem = if node == self.current {
+				unsafe { self.consume_backward().unwrap_unchecked() }.0
+			} else {
+				if pos < self.index { self.index -= 1; }
+				unsafe { self.unlink(node) }.elem
+			};
+			merge(unsafe { &mut kept.as_mut().elem }, elem);
+		}
+	}
+
+	/// Sort the list in ascending order. `O(n log n)`.  
+	/// This is a stable merge sort that only relinks the nodes, nothing is moved or reallocated.
+	/// The cursor stays on the same element, see `sort_by_keeping` to keep its index instead.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![3, 1, 4, 1, 5]);
+	/// list.move_to(2);
+	///
+	/// list.sort();
+	/// assert_eq!(format!("{:?}", list), "[1, 1, 3, 4, 5]");
+	/// assert_eq!(list.current(), Some(&4));
+	/// assert_eq!(list.index(), 3);
+	/// ```
+	#[inline]
+	pub fn sort(&mut self) where T: Ord {
+		self.sort_by_keeping(Keep::Element, T::cmp);
+	}
+
+	/// Sort the list with a comparator function. `O(n log n)`.  
+	/// Stable, and the cursor stays on the same element, just like `sort`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![3, 1, 2]);
+	///
+	/// list.sort_by(|a, b| b.cmp(a));
+	/// assert_eq!(format!("{:?}", list), "[3, 2, 1]");
+	/// ```
+	#[inline]
+	pub fn sort_by(&mut self, cmp: impl FnMut(&T, &T) -> Ordering) {
+		self.sort_by_keeping(Keep::Element, cmp);
+	}
+
+	/// Sort the list by a key extracted from each element. `O(n log n)`.  
+	/// Stable, and the cursor stays on the same element, just like `sort`.
+	/// The key is computed on every comparison.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')]);
+	///
+	/// list.sort_by_key(|&(num, _)| num);
+	/// assert_eq!(format!("{:?}", list), "[(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]");
+	/// ```
+	#[inline]
+	pub fn sort_by_key<K: Ord>(&mut self, mut f: impl FnMut(&T) -> K) {
+		self.sort_by_keeping(Keep::Element, |a, b| f(a).cmp(&f(b)));
+	}
+
+	/// Sort the list with a comparator function, choosing what the cursor sticks to. `O(n log n)`.  
+	/// Stable, and only relinks the nodes. If `cmp` panics the list is left in its original order.
+	/// ```
+	/// # use iterlist::{IterList, Keep};
+	/// let mut list = IterList::from(vec![3, 1, 2]);
+	///
+	/// list.sort_by_keeping(Keep::Index, |a, b| a.cmp(b));
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// assert_eq!(list.index(), 0);
+	/// ```
+	pub fn sort_by_keeping(&mut self, keep: Keep, mut cmp: impl FnMut(&T, &T) -> Ordering) {
+		if self.len < 2 { return; }
+
+		// Only `next` links are touched while merging, so the `prev` links can put things back on a panic.
+		struct Restore<'a, T, A: Allocator>(&'a mut IterList<T, A>);
+
+		impl<T, A: Allocator> Drop for Restore<'_, T, A> {
+			fn drop(&mut self) {
+				let mut node = self.0.back;
+				unsafe {
+					node.as_mut().next = None;
+					while let Some(mut prev) = node.as_ref().prev {
+						prev.as_mut().next = Some(node);
+						node = prev;
+					}
+				}
+			}
+		}
+
+		let guard = Restore(self);
+		let mut head = guard.0.front;
+		let mut width = 1;
+
+		// Bottom-up merge sort on the `next` links, merging runs of `width` pairwise every pass.
+		loop {
+			let mut left  = Some(head);
+			let mut tail: Option<NonNull<Node<T>>> = None;
+			let mut merges = 0;
+
+			while let Some(start) = left {
+				merges += 1;
+
+				let mut right = Some(start);
+				let mut left_len = 0;
+				while let Some(node) = right.filter(|_| left_len < width) {
+					right = unsafe { node.as_ref() }.next;
+					left_len += 1;
+				}
+				let mut right_len = width;
+
+				while left_len > 0 || (right_len > 0 && right.is_some()) {
+					let take_left = match (left, right.filter(|_| right_len > 0)) {
+						(Some(l), Some(r)) if left_len > 0 => unsafe { cmp(&l.as_ref().elem, &r.as_ref().elem) }.is_le(),
+						_ => left_len > 0,
+					};
+
+					let node = unsafe {
+						if take_left {
+							let node = left.unwrap_unchecked();
+							left = node.as_ref().next;
+							left_len -= 1;
+							node
+						} else {
+							let node = right.unwrap_unchecked();
+							right = node.as_ref().next;
+							right_len -= 1;
+							node
+						}
+					};
+
+					match tail {
+						Some(mut tail) => unsafe { tail.as_mut().next = Some(node) },
+						None => head = node,
+					}
+					tail = Some(node);
+				}
+
+				left = right;
+			}
+
+			unsafe { tail.unwrap_unchecked().as_mut().next = None; }
+			if merges <= 1 { break; }
+			width *= 2;
+		}
+
+		mem::forget(guard);
+
+		// Put the `prev` links and both ends back, finding where the cursor goes on the way.
+		let (old, old_index) = (self.current, self.index);
+		let mut prev: Option<NonNull<Node<T>>> = None;
+		let mut node = Some(head);
+		let mut index = 0;
+
+		while let Some(mut n) = node {
+			unsafe { n.as_mut().prev = prev; }
+			match keep {
+				Keep::Element if n == old           => self.index   = index,
+				Keep::Index   if index == old_index => self.current = n,
+				_ => (),
+			}
+			prev = Some(n);
+			node = unsafe { n.as_ref() }.next;
+			index += 1;
+		}
+
+		self.front = head;
+		self.back  = unsafe { prev.unwrap_unchecked() };
+		self.version += 1;
+	}
+}
+
+impl<T> IterList<T> {
+	/// Merge another sorted list into this sorted one. `O(n + m)`.  
+	/// Nodes of `other` are relinked in one by one, so nothing is reallocated.
+	/// The merge is stable, on ties elements of this list come first.  
+	/// The cursor stays on the same element, if this list was empty it takes over the cursor of `other`.
+	/// If `cmp` panics, both lists are left consistent but only partially merged.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 3, 5, 7]);
+	/// list.move_to(2);
+	///
+	/// list.merge(IterList::from(vec![2, 3, 4, 8]), |a, b| a.cmp(b));
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3, 3, 4, 5, 7, 8]");
+	/// assert_eq!(list.current(), Some(&5));
+	/// assert_eq!(list.index(), 5);
+	/// ```
+	pub fn merge(&mut self, mut other: Self, mut cmp: impl FnMut(&T, &T) -> Ordering) {
+		if other.is_empty() { return; }
+		if self.is_empty() { return self.take_over(other); }
+
+		let mut at  = Some(self.front);
+		let mut pos = 0;
+
+		while !other.is_empty() {
+			let node = other.front;
+
+			while let Some(a) = at {
+				if unsafe { cmp(&a.as_ref().elem, &node.as_ref().elem) }.is_gt() { break; }
+				at   = unsafe { a.as_ref() }.next;
+				pos += 1;
+			}
+
+			let Some(a) = at else { return self.append(other) };
+
+			unsafe {
+				other.forget_node(node);
+				other.detach(node);
+				self.link_before(node, a);
+			}
+
+			if pos <= self.index { self.index += 1; }
+			pos += 1;
+		}
+	}
+
+	/// Merge any number of sorted lists into one. `O(n log k)` for `k` lists.  
+	/// Lists are merged pairwise, so the result is stable: on ties elements of earlier lists come first.
+	/// The cursor ends up on the element the cursor of the first non-empty list was on.
 	/// ```
 	/// # use iterlist::IterList;
-	/// let mut list = IterList::from(vec![1, 2, 3]);
-	/// list.move_by(1);
-	/// assert_eq!(list.consume_backward(), Some((2, true)));
-	/// assert_eq!(&format!("{:?}", list), "[1, 3]");
-	/// assert_eq!(list.current(), Some(&1));
+	/// let lists = [vec![1, 4, 7], vec![2, 5, 8], vec![3, 6, 9]].map(IterList::from);
+	///
+	/// let list = IterList::merge_all(lists, |a, b| a.cmp(b));
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3, 4, 5, 6, 7, 8, 9]");
 	/// ```
-	pub fn consume_backward(&mut self) -> Option<(T, bool)> {
-		if self.len == 0 { return None; }
+	pub fn merge_all(lists: impl IntoIterator<Item = Self>, mut cmp: impl FnMut(&T, &T) -> Ordering) -> Self {
+		let mut lists: Vec<Self> = lists.into_iter().collect();
 
-		let node = unsafe { Box::from_raw(self.current.as_ptr()) };
+		while lists.len() > 1 {
+			let mut merged = Vec::with_capacity(lists.len().div_ceil(2));
+			let mut lists_iter = lists.into_iter();
 
-		self.len -= 1;
-		self.index -= 1;
-		node.next.map(|mut next| unsafe { next.as_mut().prev = node.prev });
+			while let Some(mut list) = lists_iter.next() {
+				if let Some(other) = lists_iter.next() { list.merge(other, &mut cmp); }
+				merged.push(list);
+			}
+			lists = merged;
+		}
 
-		match node.prev {
-			Some(mut prev) => {
-				unsafe { prev.as_mut().next = node.next; }
-				self.current = prev;
-				Some((node.elem, true))
-			},
-			None => {
-				self.current = node.next.unwrap_or(NonNull::dangling());
-				Some((node.elem, false))
+		lists.pop().unwrap_or_default()
+	}
+}
+
+impl<T, A: Allocator> IterList<T, A> {
+	/// Unlink the elements in `start..end` into a list of their own. `O(n)`.  
+	/// The cursor is moved off the range as described in `drain`.
+	fn unlink_range(&mut self, start: usize, end: usize) -> Self where A: Clone {
+		if start == end { return self.sibling(); }
+
+		let from = Some((self.index, self.current));
+		unsafe { self.unlink_nodes(
+			self.node_at(start,   from).unwrap_unchecked(),
+			self.node_at(end - 1, from).unwrap_unchecked(),
+			start, end,
+		) }
+	}
+
+	/// Unlink the nodes from `first` to `last` into a list of their own.
+	/// `O(1)`, plus `O(end - start)` if handles were given out.
+	///
+	/// # Safety
+	/// `first` and `last` must belong to this list, at the indices `start` and `end - 1`.
+	unsafe fn unlink_nodes(&mut self, mut first: NonNull<Node<T>>, mut last: NonNull<Node<T>>, start: usize, end: usize) -> Self where A: Clone {
+		let mut out = self.sibling();
+		let count = end - start;
+		let (prev, next) = (first.as_ref().prev, last.as_ref().next);
+
+		match prev {
+			Some(mut prev) => prev.as_mut().next = next,
+			None => self.front = next.unwrap_or(NonNull::dangling()),
+		}
+		match next {
+			Some(mut next) => next.as_mut().prev = prev,
+			None => self.back = prev.unwrap_or(NonNull::dangling()),
+		}
+
+		first.as_mut().prev = None;
+		last .as_mut().next = None;
+
+		self.forget_nodes(first, count);
+		self.len -= count;
+		self.version += 1;
+
+		if self.index >= end {
+			self.index -= count;
+		} else if self.index >= start {
+			match (next, prev) {
+				(Some(next), _) => { self.current = next; self.index = start; },
+				(None, Some(prev)) => { self.current = prev; self.index = start - 1; },
+				(None, None) => { self.current = NonNull::dangling(); self.index = 0; },
 			}
 		}
+
+		out.current = first;
+		out.front   = first;
+		out.back    = last;
+		out.len     = count;
+		out
 	}
 
-	/// Replace the current element with a new one. `O(1)`.  
-	/// Returns the old element.  
-	/// If the list is empty, the new element will be inserted, and `None` returned.
-	/// ```
-	/// # use iterlist::IterList;
-	/// let mut list = IterList::from(vec![1, 2, 3]);
-	/// assert_eq!(list.replace_cursor(4), Some(1));
-	/// assert_eq!(format!("{:?}", list), "[4, 2, 3]");
-	/// ```
+	/// Give up the nodes after they have been relinked into another list.
+	/// Handles given out by this list, its pool and its allocator are dropped along with it.
+	fn release(mut self) {
+		self.handles = None;
+		self.pool    = None;
+		let list = mem::ManuallyDrop::new(self);
+		mem::drop(unsafe { std::ptr::read(&list.alloc) });
+	}
+
+	/// Replace this empty list with `other`, keeping the id so cursors taken from it come out stale, not foreign.
+	/// Handles given out by `other` are dropped, while those of this list keep their generation counter,
+	/// so a stale one can't come back to life on a new node. The pool of this list is kept if it has one.
+	fn take_over(&mut self, mut other: Self) {
+		*other.id.get_mut() = *self.id.get_mut();
+		other.version = self.version + 1;
+		other.handles = self.handles.take();
+		other.pool    = self.pool.take().or_else(|| other.pool.take());
+		*self = other;
+	}
+
+	/// A new empty list, sharing the pool of this one.
 	#[inline]
-	pub fn replace_cursor(&mut self, elem: T) -> Option<T> {
-		match self.len {
-			0 => { self.push_next(elem); None },
-			_ => Some(std::mem::replace(unsafe { &mut self.current.as_mut().elem }, elem)),
+	fn sibling(&self) -> Self where A: Clone {
+		let mut list = Self::new_in(self.alloc.clone());
+		list.pool = self.pool.clone();
+		list
+	}
+
+	/// Splicing into an empty list, just take over `other`.
+	fn take_spliced(&mut self, other: Self, cursor: Splice) {
+		self.take_over(other);
+		match cursor {
+			Splice::Stay  => (),
+			Splice::First => { self.move_to_front(); },
+			Splice::Last  => { self.move_to_back(); },
 		}
 	}
 
-	/// Apply the current state of a cursor to the list. `O(n)`.
+	/// Detach a node from its neighbours and take ownership of it, giving its memory back. `O(1)`.
 	///
 	/// # Safety
-	/// The cursor must be valid and point to a `Node` in the same list.
+	/// Same as `detach`.
 	#[inline]
-	pub unsafe fn apply_cursor_unchecked(&mut self, cursor: &Cursor<T>) {
-		self.current = cursor.current.unwrap_unchecked();
-		self.index   = cursor.index;
+	unsafe fn unlink(&mut self, node: NonNull<Node<T>>) -> Node<T> {
+		self.forget_node(node);
+		self.detach(node);
+		self.free_node(node)
 	}
 
-	#[inline]
-	pub fn apply_cursor(&mut self, cursor: &Cursor<T>) -> bool {
-		if self.index + cursor.index >= self.len { return false; }
-
-		match self.index.cmp(&cursor.index) {
-			Ordering::Greater => (0..self.index - cursor.index)
-				.try_fold(self.current, |ptr, _| unsafe { ptr.as_ref() }.prev.map(|c| { self.current = c; ptr }))
-				.map(|p| { self.current = p; self.index = cursor.index }).is_some(),
-			Ordering::Less    => (0..cursor.index - self.index)
-				.try_fold(self.current, |ptr, _| unsafe { ptr.as_ref() }.next.map(|c| { self.current = c; ptr }))
-				.map(|p| { self.current = p; self.index = cursor.index }).is_some(),
-			Ordering::Equal   => true,
+	/// Detach a node from its neighbours, fixing up both ends and the length. `O(1)`.  
+	/// The node itself is left untouched, so it can be linked in somewhere else.
+	/// Its handles are kept, call `forget_node` if it's leaving the list.
+	///
+	/// # Safety
+	/// `node` must belong to this list. The cursor is left alone,
+	/// so it is up to the caller to move it off `node`.
+	unsafe fn detach(&mut self, mut node: NonNull<Node<T>>) {
+		self.version += 1;
+		let node = node.as_mut();
+
+		match node.prev {
+			Some(mut prev) => prev.as_mut().next = node.next,
+			None => self.front = node.next.unwrap_or(NonNull::dangling()),
+		}
+
+		match node.next {
+			Some(mut next) => next.as_mut().prev = node.prev,
+			None => self.back = node.prev.unwrap_or(NonNull::dangling()),
 		}
+
+		self.len -= 1;
 	}
 
-	/// Split the list after the cursor. `O(1)`.  
-	/// If the list is empty, or the cursor is at the end, `None` will be returned.  
-	/// ```
-	/// # use iterlist::IterList;
-	/// let mut list = IterList::from(vec![1, 2, 3]);
-	/// list.advance();
-	/// let new_list = list.split_after().unwrap();
-	///
-	/// assert_eq!(format!("{:?}", list), "[1, 2]");
-	/// assert_eq!(format!("{:?}", new_list), "[3]");
-	/// assert_eq!(new_list.index(), 0);
-	/// ```
-	pub fn split_after(&mut self) -> Option<Self> {
-		if self.len == 0 { return None; }
+	/// Link a new node in after the cursor, or make it the only one. `O(1)`.
+	fn link_next(&mut self, mut new: NonNull<Node<T>>) {
+		let fresh = self.labels_fresh();
+		self.version += 1;
 
-		unsafe { self.current.as_ref() }.next.map(|next| {
-			let mut new = Self::new();
-			new.current = next;
+		match self.len {
+			0 => self.reset_to(new),
+			_ => {
+				unsafe {
+					match self.current.as_mut().next {
+						Some(mut next) => {
+							next.as_mut().prev = Some(new);
+							new.as_mut().next  = Some(next);
+						},
+						None => self.back = new,
+					}
 
-			new.len = self.len - self.index - 1;
-			self.len -= new.len;
+					self.current.as_mut().next = Some(new);
+					new.as_mut().prev          = Some(self.current);
+				}
+			},
+		}
 
-			unsafe { self.current.as_mut().next = None; }
-			unsafe { new .current.as_mut().prev = None; }
+		self.len += 1;
+		self.label_linked(new, fresh);
+	}
 
-			new
-		})
+	/// Link a new node in before the cursor, or make it the only one. `O(1)`.
+	fn link_prev(&mut self, mut new: NonNull<Node<T>>) {
+		let fresh = self.labels_fresh();
+		self.version += 1;
+
+		match self.len {
+			0 => self.reset_to(new),
+			_ => {
+				unsafe {
+					match self.current.as_mut().prev {
+						Some(mut prev) => {
+							prev.as_mut().next = Some(new);
+							new.as_mut().prev  = Some(prev);
+						},
+						None => self.front = new,
+					}
+
+					self.current.as_mut().prev = Some(new);
+					new.as_mut().next          = Some(self.current);
+				}
+
+				self.index += 1;
+			},
+		}
+
+		self.len += 1;
+		self.label_linked(new, fresh);
 	}
 
-	/// Split the list before the cursor. `O(1)`.
-	/// If the list is empty, or the cursor is at the front, `None` will be returned.
-	/// ```
-	/// # use iterlist::IterList;
-	/// let mut list = IterList::from(vec![1, 2, 3, 4]);
-	/// list.move_by(2);
-	/// let new_list = list.split_before().unwrap();
+	/// Link a detached node in right before `at`. `O(1)`.  
+	/// The cursor is left alone, so it is up to the caller to fix its index.
 	///
-	/// assert_eq!(format!("{:?}", list), "[3, 4]");
-	/// assert_eq!(format!("{:?}", new_list), "[1, 2]");
-	/// assert_eq!(new_list.index(), 1);
-	/// assert_eq!(list.index(), 0);
-	/// ```
-	pub fn split_before(&mut self) -> Option<Self> {
-		if self.len == 0 { return None; }
+	/// # Safety
+	/// `at` must belong to this list, and `node` to no list at all.
+	unsafe fn link_before(&mut self, mut node: NonNull<Node<T>>, mut at: NonNull<Node<T>>) {
+		node.as_mut().prev = at.as_ref().prev;
+		node.as_mut().next = Some(at);
+
+		match at.as_ref().prev {
+			Some(mut prev) => prev.as_mut().next = Some(node),
+			None => self.front = node,
+		}
+		at.as_mut().prev = Some(node);
 
-		unsafe { self.current.as_ref() }.prev.map(|prev| {
-			let mut new = Self::new();
+		self.len += 1;
+		self.version += 1;
+	}
 
-			new.current = prev;
-			new.len = self.index;
-			self.len -= new.len;
-			self.index = 0;
-			new.index = new.len - 1;
+	/// Link a detached node in right after `at`. `O(1)`.  
+	/// The cursor is left alone, so it is up to the caller to fix its index.
+	///
+	/// # Safety
+	/// `at` must belong to this list, and `node` to no list at all.
+	unsafe fn link_after(&mut self, mut node: NonNull<Node<T>>, mut at: NonNull<Node<T>>) {
+		node.as_mut().next = at.as_ref().next;
+		node.as_mut().prev = Some(at);
+
+		match at.as_ref().next {
+			Some(mut next) => next.as_mut().prev = Some(node),
+			None => self.back = node,
+		}
+		at.as_mut().next = Some(node);
 
-			unsafe { self.current.as_mut().prev = None; }
-			unsafe { new .current.as_mut().next = None; }
+		self.len += 1;
+		self.version += 1;
+	}
 
-			new
-		})
+	/// Point the cursor and both ends at a single node.
+	#[inline]
+	fn reset_to(&mut self, node: NonNull<Node<T>>) {
+		self.current = node;
+		self.front   = node;
+		self.back    = node;
+		self.index   = 0;
 	}
 
 	/// Get a ref to the current element. `O(1)`.
@@ -588,6 +2136,57 @@ impl<T> IterList<T> {
 		self.is_empty().not().then_some(unsafe { &mut self.current.as_mut().elem })
 	}
 
+	/// Get a ref to the first element. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// list.move_to_back();
+	///
+	/// assert_eq!(list.front(), Some(&1));
+	/// ```
+	#[inline]
+	pub fn front(&self) -> Option<&T> {
+		self.is_empty().not().then_some(unsafe { &self.front.as_ref().elem })
+	}
+
+	/// Get a mut ref to the first element. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	///
+	/// *list.front_mut().unwrap() = 4;
+	/// assert_eq!(format!("{:?}", list), "[4, 2, 3]");
+	/// ```
+	#[inline]
+	pub fn front_mut(&mut self) -> Option<&mut T> {
+		self.is_empty().not().then_some(unsafe { &mut self.front.as_mut().elem })
+	}
+
+	/// Get a ref to the last element. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	///
+	/// assert_eq!(list.back(), Some(&3));
+	/// ```
+	#[inline]
+	pub fn back(&self) -> Option<&T> {
+		self.is_empty().not().then_some(unsafe { &self.back.as_ref().elem })
+	}
+
+	/// Get a mut ref to the last element. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	///
+	/// *list.back_mut().unwrap() = 4;
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 4]");
+	/// ```
+	#[inline]
+	pub fn back_mut(&mut self) -> Option<&mut T> {
+		self.is_empty().not().then_some(unsafe { &mut self.back.as_mut().elem })
+	}
+
 	/// Get the number of elements in the list. `O(1)`.
 	/// ```
 	/// # use iterlist::IterList;
@@ -636,11 +2235,30 @@ impl<T> IterList<T> {
 	/// assert_eq!(list.current(), Some(&1));
 	/// ```
 	#[inline]
-	pub fn as_cursor(&self) -> Cursor<T> {
+	pub fn as_cursor(&self) -> Cursor<'_, T, A> {
+		self.cursor_at(self.current, self.index)
+	}
+
+	/// A cursor tied to this list, stamped with its id and modification count.
+	fn cursor_at(&self, current: NonNull<Node<T>>, index: usize) -> Cursor<'_, T, A> {
 		Cursor {
-			_list:   PhantomData,
-			index:   self.index,
-			current: self.is_empty().not().then_some(self.current),
+			current: self.is_empty().not().then_some(current),
+			index,
+			list:    Some(self),
+			id:      self.id(),
+			version: self.version,
+		}
+	}
+
+	/// The id of this list, handing out a new one the first time it's needed. `O(1)`.
+	fn id(&self) -> u64 {
+		match self.id.load(AtomicOrdering::Relaxed) {
+			0 => {
+				let id = NEXT_ID.fetch_add(1, AtomicOrdering::Relaxed);
+				self.id.compare_exchange(0, id, AtomicOrdering::Relaxed, AtomicOrdering::Relaxed)
+					.map_or_else(|id| id, |_| id)
+			},
+			id => id,
 		}
 	}
 }
@@ -648,7 +2266,7 @@ impl<T> IterList<T> {
 
 
 
-impl<T> std::ops::Index<isize> for IterList<T> {
+impl<T, A: Allocator> std::ops::Index<isize> for IterList<T, A> {
 	type Output = T;
 
 	/// Essentially equivalent to `get`. `O(n)`.  
@@ -660,23 +2278,23 @@ impl<T> std::ops::Index<isize> for IterList<T> {
 	}
 }
 
-impl<T> Default for IterList<T> {
+impl<T, A: Allocator + Default> Default for IterList<T, A> {
 	#[inline]
 	fn default() -> Self {
-		Self::new()
+		Self::new_in(A::default())
 	}
 }
 
-impl<T> std::ops::IndexMut<isize> for IterList<T> {
+impl<T, A: Allocator> std::ops::IndexMut<isize> for IterList<T, A> {
 	#[inline]
 	fn index_mut(&mut self, index: isize) -> &mut Self::Output {
 		self.get_mut(index).expect("Index out of bounds")
 	}
 }
 
-impl<T: Clone> Clone for IterList<T> {
+impl<T: Clone, A: Allocator + Clone> Clone for IterList<T, A> {
 	/// Clone the list. `O(n)`.  
-	/// Cursor position is retained.
+	/// Cursor position is retained, and the clone shares the pool if there is one.
 	/// ```
 	/// # use iterlist::IterList;
 	/// let list = vec![1, 2, 3].into_iter().collect::<IterList<_>>();
@@ -690,14 +2308,14 @@ impl<T: Clone> Clone for IterList<T> {
 		cursor.move_to_front();
 
 		let mut list = cursor.cloned()
-			.fold(Self::new(), |mut list, elem| { list.push_next(elem); list });
+			.fold(self.sibling(), |mut list, elem| { list.push_next(elem); list });
 
 		let _ = list.move_to(self.index());
 		list
 	}
 }
 
-impl<T: Debug> Debug for IterList<T> {
+impl<T: Debug, A: Allocator> Debug for IterList<T, A> {
 	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
 		write!(f, "[")?;
 
@@ -719,16 +2337,19 @@ impl<T: Debug> Debug for IterList<T> {
 	}
 }
 
-impl<T> Drop for IterList<T> {
+impl<T, A: Allocator> Drop for IterList<T, A> {
 	#[inline]
 	/// Drop the list. `O(n)`.
 	fn drop(&mut self) {
 		if self.is_empty() { return; }
 
-		self.move_to_front();
+		// no one else could reuse the nodes
+		if self.pool.as_ref().is_some_and(|pool| !pool.is_shared()) { self.pool = None; }
+
+		self.current = self.front;
 		loop {
 			let next = unsafe { self.current.as_ref().next };
-			mem::drop(unsafe { Box::from_raw(self.current.as_ptr()) });
+			mem::drop(unsafe { self.free_node(self.current) });
 			match next {
 				Some(next) => self.current = next,
 				None => break,
@@ -744,51 +2365,6 @@ impl<T> Drop for IterList<T> {
 * ==========================
 */
 
-impl<T> Iterator for IterList<T> {
-	type Item = T;
-
-	/// Internally this call is just `consume_forward`. `O(1)`.
-	/// ```
-	/// # use iterlist::IterList;
-	/// let list = IterList::from(vec![1, 2, 3]);
-	///
-	/// // list moved
-	/// let num = list.fold(0, |acc, elem| acc + elem);
-	/// assert_eq!(num, 6);
-	/// ```
-	#[inline]
-	fn next(&mut self) -> Option<Self::Item> {
-		self.consume_forward().map(|(elem, b)| {
-			if !b { while self.consume_backward().is_some() {} }
-			elem
-		})
-	}
-
-	#[inline]
-	fn size_hint(&self) -> (usize, Option<usize>) 
-		{ (self.len, Some(self.len)) }
-}
-
-impl<T> DoubleEndedIterator for IterList<T> {
-	/// Internally this call is just `consume_backward`. `O(1)`.
-	/// ```
-	/// # use iterlist::IterList;
-	/// let mut list = IterList::from(vec![1, 2, 3]);
-	///
-	/// list.move_to_back();
-	///
-	/// let two = list.nth_back(1);
-	/// assert_eq!(two, Some(2));
-	/// ```
-	#[inline]
-	fn next_back(&mut self) -> Option<Self::Item> {
-		self.consume_backward().map(|(elem, b)| {
-			if !b { while self.consume_forward().is_some() {} }
-			elem
-		})
-	}
-}
-
 impl<T> From<Vec<T>> for IterList<T> {
 	/// Create a new list from a Vec. `O(n)`.  
 	/// Cursor is set to the front of the list.
@@ -852,8 +2428,9 @@ impl<T> FromIterator<T> for IterList<T> {
 /// A copy of a cursor of an IterList.  
 /// Allows for traversing the list without modifying the original.  
 ///
-/// Internally, the cursor is a fat pointer to the current element,
-/// so the size of a `Cursor` is two words.  
+/// Internally, the cursor is a fat pointer to the current element plus a ref to the list it came from,
+/// and that list's id and modification count, so the size of a `Cursor` is five words.  
+/// Cursors not tied to a list (`new`, `from_raw`, `new_dangling`) have to walk to reach either end.  
 /// ```
 /// # use iterlist::IterList;
 /// let list = IterList::from(vec![1, 2, 3]);
@@ -866,21 +2443,23 @@ impl<T> FromIterator<T> for IterList<T> {
 /// assert_eq!(list.current(), Some(&1));
 /// ```
 #[derive(Clone, Copy)]
-pub struct Cursor<'i, T> {
+pub struct Cursor<'i, T, A: Allocator = Global> {
 	current: Option<NonNull<Node<T>>>,
 	index:   usize,
-	_list:   PhantomData<&'i T>,
+	list:    Option<&'i IterList<T, A>>,
+	id:      u64,
+	version: u64,
 }
 
-unsafe impl<T: Send> Send for Cursor<'_, T> {}
-unsafe impl<T: Sync> Sync for Cursor<'_, T> {}
+unsafe impl<T: Send, A: Allocator + Sync> Send for Cursor<'_, T, A> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for Cursor<'_, T, A> {}
 
-impl<'i, T> Iterator for Cursor<'i, T> {
+impl<'i, T, A: Allocator> Iterator for Cursor<'i, T, A> {
 	type Item = &'i T;
 
 	#[inline]
 	fn next(&mut self) -> Option<Self::Item> {
-		self.current.map(|c| {
+		self.node().map(|c| {
 			self.current = unsafe { c.as_ref().next };
 			self.index += 1;
 			unsafe { &c.as_ref().elem }
@@ -892,7 +2471,7 @@ impl<'i, T> Iterator for Cursor<'i, T> {
 		{ (self.index, None) }
 }
 
-impl<'t, T> Cursor<'t, T> {
+impl<'t, T, A: Allocator> Cursor<'t, T, A> {
 	/// Create a new cursor from a raw pointer. `O(1)`.
 	///
 	/// # Safety
@@ -902,7 +2481,9 @@ impl<'t, T> Cursor<'t, T> {
 		Self {
 			current: Some(NonNull::new_unchecked(ptr as *mut Node<T>)),
 			index:   0,
-			_list:   PhantomData,
+			list:    None,
+			id:      0,
+			version: 0,
 		}
 	}
 
@@ -916,7 +2497,9 @@ impl<'t, T> Cursor<'t, T> {
 		Self {
 			current: Some(NonNull::dangling()),
 			index:   0,
-			_list:   PhantomData,
+			list:    None,
+			id:      0,
+			version: 0,
 		}
 	}
 
@@ -927,7 +2510,9 @@ impl<'t, T> Cursor<'t, T> {
 		Self {
 			current: None,
 			index:   0,
-			_list:   PhantomData,
+			list:    None,
+			id:      0,
+			version: 0,
 		}
 	}
 
@@ -935,21 +2520,50 @@ impl<'t, T> Cursor<'t, T> {
 	/// The new cursor is an exact copy of the list's cursor.
 	/// 
 	#[inline]
-	pub fn from(list: &'t IterList<T>) -> Self {
+	pub fn from(list: &'t IterList<T, A>) -> Self {
 		assert!(!list.is_empty(), "Cannot create a cursor from an empty list");
-		Self {
-			current: Some(list.current),
-			index:   list.index,
-			_list:   PhantomData,
-		}
+		list.as_cursor()
 	}
 
 	/// Update the cursor to match the current state of the list. `O(1)`.  
 	/// Useful if you lose track of the list, or want to use the same cursor on multiple lists.
 	#[inline]
-	pub fn reacquire(&mut self, list: &'t IterList<T>) {
-		self.current = (list.len != 0).then_some(list.current);
-		self.index   = list.index;
+	pub fn reacquire(&mut self, list: &'t IterList<T, A>) {
+		*self = list.as_cursor();
+	}
+
+	/// Let go of the list, keeping only the position. `O(1)`.  
+	/// A detached cursor can't read or move anymore, but it can be handed back to `IterList::apply_cursor`,
+	/// which checks that the list hasn't been modified in the meantime.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// let cursor = list.as_cursor().detach();
+	/// assert_eq!(cursor.current(), None);
+	///
+	/// list.move_to_back();
+	/// assert!(list.apply_cursor(&cursor).is_ok());
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	#[inline]
+	pub fn detach(self) -> Cursor<'static, T, A> {
+		Cursor {
+			current: self.current,
+			index:   self.index,
+			list:    None,
+			id:      self.id,
+			version: self.version,
+		}
+	}
+
+	/// The current node, if it can be read from.
+	/// Detached cursors keep their node only to be applied, it may be gone by now.
+	#[inline]
+	fn node(&self) -> Option<NonNull<Node<T>>> {
+		match (self.list, self.id) {
+			(None, 1..) => None,
+			_ => self.current,
+		}
 	}
 
 	/// Get a ref to the current element. `O(1)`.
@@ -962,7 +2576,7 @@ impl<'t, T> Cursor<'t, T> {
 	/// ```
 	#[inline]
 	pub fn current(&self) -> Option<&T> {
-		self.current.map(|c| unsafe { &c.as_ref().elem })
+		self.node().map(|c| unsafe { &c.as_ref().elem })
 	}
 
 	/// Get the index of the cursor `O(1)`.
@@ -979,8 +2593,8 @@ impl<'t, T> Cursor<'t, T> {
 		self.index
 	}
 
-	/// Move the cursor to the front of the list. `O(n)`.  
-	/// Returns the number of elements traversed.
+	/// Move the cursor to the front of the list. `O(1)`, or `O(n)` if the cursor is not tied to a list.  
+	/// Returns the number of elements skipped over.
 	/// ```
 	/// # use iterlist::IterList;
 	/// let list = IterList::from(vec![1, 2, 3]);
@@ -994,6 +2608,12 @@ impl<'t, T> Cursor<'t, T> {
 	/// assert_eq!(cursor.current(), Some(&1));
 	/// ```
 	pub fn move_to_front(&mut self) -> usize {
+		if let Some(list) = self.list {
+			self.current = list.is_empty().not().then_some(list.front);
+			return mem::take(&mut self.index);
+		}
+
+		if self.node().is_none() { return 0; }
 		self.index = 0;
 
 		for i in 0_usize.. {
@@ -1005,8 +2625,8 @@ impl<'t, T> Cursor<'t, T> {
 		unsafe { std::hint::unreachable_unchecked() }
 	}
 
-	/// Move the cursor to the back of the list. `O(n)`.  
-	/// Returns the number of elements traversed.
+	/// Move the cursor to the back of the list. `O(1)`, or `O(n)` if the cursor is not tied to a list.  
+	/// Returns the number of elements skipped over.
 	/// ```
 	/// # use iterlist::IterList;
 	/// let list = IterList::from(vec![1, 2, 3]);
@@ -1017,11 +2637,22 @@ impl<'t, T> Cursor<'t, T> {
 	/// assert_eq!(cursor.current(), Some(&3));
 	/// ```
 	pub fn move_to_back(&mut self) -> usize {
+		if let Some(list) = self.list {
+			if list.is_empty() { return 0; }
+
+			let skipped = (list.len - 1).saturating_sub(self.index);
+			self.current = Some(list.back);
+			self.index   = list.len - 1;
+			return skipped;
+		}
+
+		if self.node().is_none() { return 0; }
+
 		for i in 0_usize.. {
 			match self.current.and_then(|c| unsafe { c.as_ref().next }) {
 				Some(next) => self.current = Some(next),
 				None => {
-					self.index = i;
+					self.index += i;
 					return i;
 				},
 			}
@@ -1030,6 +2661,7 @@ impl<'t, T> Cursor<'t, T> {
 	}
 
 	/// Move the cursor to the specified index. `O(n)`.
+	/// If the cursor is tied to a list, it walks from whichever of the front, the back or the cursor is closest.  
 	/// If the index is out of bounds the cursor will be moved to the edge, 
 	/// and `false` will be returned.
 	/// ```
@@ -1040,11 +2672,20 @@ impl<'t, T> Cursor<'t, T> {
 	/// cursor.move_to(1);
 	/// assert_eq!(cursor.current(), Some(&2));
 	/// ```
-	/// # Panics
-	/// Panics if the index is out of bounds.
 	#[inline]
 	#[must_use]
 	pub fn move_to(&mut self, index: usize) -> bool {
+		if let Some(list) = self.list {
+			return match list.node_at(index, self.current.map(|c| (self.index, c))) {
+				Some(node) => {
+					self.current = Some(node);
+					self.index   = index;
+					true
+				},
+				None => { self.move_to_back(); false },
+			};
+		}
+
 		match self.index.cmp(&index) {
 			Ordering::Greater => !(0..self.index - index).any(|_| !self.retreat()),
 			Ordering::Less    => !(0..index - self.index).any(|_| !self.advance()),
@@ -1067,7 +2708,7 @@ impl<'t, T> Cursor<'t, T> {
 	#[inline]
 	#[must_use]
 	pub fn advance(&mut self) -> bool {
-		self.current.and_then(|c| unsafe { c.as_ref().next }).map(|next| {
+		self.node().and_then(|c| unsafe { c.as_ref().next }).map(|next| {
 				self.current = Some(next);
 				self.index += 1; })
 			.is_some()
@@ -1089,7 +2730,7 @@ impl<'t, T> Cursor<'t, T> {
 	#[inline]
 	#[must_use]
 	pub fn retreat(&mut self) -> bool {
-		self.current.and_then(|c| unsafe { c.as_ref().prev }).map(|prev| {
+		self.node().and_then(|c| unsafe { c.as_ref().prev }).map(|prev| {
 				self.current = Some(prev);
 				self.index -= 1; })
 			.is_some()
@@ -1135,15 +2776,28 @@ impl<'t, T> Cursor<'t, T> {
 	/// assert_eq!(cursor.get(-1), None);
 	/// ```
 	pub fn get(&self, offset: isize) -> Option<&T> {
+		if let Some(list) = self.list {
+			return self.index.checked_add_signed(offset)
+				.and_then(|index| list.node_at(index, self.current.map(|c| (self.index, c))))
+				.map(|c| unsafe { &c.as_ref().elem });
+		}
+
 		match offset.cmp(&0) {
-			Ordering::Greater => (0.. offset).try_fold(self.current, |mut ptr, _| ptr.and_then(|c| unsafe { c.as_ref().next }).map(|c| { ptr = Some(c); ptr }))?,
-			Ordering::Less    => (0..-offset).try_fold(self.current, |mut ptr, _| ptr.and_then(|c| unsafe { c.as_ref().prev }).map(|c| { ptr = Some(c); ptr }))?,
-			Ordering::Equal   => self.current
+			Ordering::Greater => (0.. offset).try_fold(self.node(), |mut ptr, _| ptr.and_then(|c| unsafe { c.as_ref().next }).map(|c| { ptr = Some(c); ptr }))?,
+			Ordering::Less    => (0..-offset).try_fold(self.node(), |mut ptr, _| ptr.and_then(|c| unsafe { c.as_ref().prev }).map(|c| { ptr = Some(c); ptr }))?,
+			Ordering::Equal   => self.node()
 		}.map(|c| unsafe { &c.as_ref().elem })
 	}
 }
 
-impl<T> std::ops::Deref for Cursor<'_, T> {
+impl<T, A: Allocator> Default for Cursor<'_, T, A> {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T, A: Allocator> std::ops::Deref for Cursor<'_, T, A> {
 	type Target = T;
 
 	#[inline]
@@ -1153,7 +2807,7 @@ impl<T> std::ops::Deref for Cursor<'_, T> {
 }
 
 
-impl<T> std::ops::Index<isize> for Cursor<'_, T> {
+impl<T, A: Allocator> std::ops::Index<isize> for Cursor<'_, T, A> {
 	type Output = T;
 
 	/// Essentially equivalent to `get`. `O(n)`.  
@@ -1165,7 +2819,7 @@ impl<T> std::ops::Index<isize> for Cursor<'_, T> {
 	}
 }
 
-impl<T: Debug> Debug for Cursor<'_, T> {
+impl<T: Debug, A: Allocator> Debug for Cursor<'_, T, A> {
 	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
 		write!(f, "{}: {:?}", self.index, self.current())
 	}
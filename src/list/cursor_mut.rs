@@ -0,0 +1,507 @@
+use std::ptr::NonNull;
+use std::ops::Not;
+use std::mem;
+
+use allocator_api2::alloc::{Allocator, Global};
+
+use super::{IterList, Cursor, Node, Splice};
+
+/// A mutable cursor into an IterList.
+/// Has its own position, independent of the list's cursor, and can edit the list around it.
+///
+/// The list's own cursor is kept valid through any edit:
+/// it shifts along when elements are added or removed in front of it,
+/// and follows this cursor if its element gets removed or split off.
+/// ```
+/// # use iterlist::IterList;
+/// let mut list = IterList::from(vec![1, 2, 4]);
+/// let mut cursor = list.cursor_mut();
+///
+/// cursor.move_to_back();
+/// cursor.insert_prev(3);
+/// assert_eq!(cursor.current(), Some(&4));
+/// assert_eq!(cursor.index(), 3);
+///
+/// assert_eq!(format!("{:?}", list), "[1, 2, 3, 4]");
+/// assert_eq!(list.current(), Some(&1));
+/// ```
+pub struct CursorMut<'a, T, A: Allocator = Global> {
+	pub(super) current: NonNull<Node<T>>,
+	pub(super) index:   usize,
+	pub(super) list:    &'a mut IterList<T, A>,
+}
+
+unsafe impl<T: Send, A: Allocator + Send> Send for CursorMut<'_, T, A> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for CursorMut<'_, T, A> {}
+
+impl<T, A: Allocator> IterList<T, A> {
+	/// Create a mutable cursor, starting at the list's cursor. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// let mut cursor = list.cursor_mut();
+	///
+	/// cursor.advance();
+	/// cursor.consume_forward();
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 3]");
+	/// assert_eq!(list.len(), 2);
+	/// ```
+	#[inline]
+	pub fn cursor_mut(&mut self) -> CursorMut<'_, T, A> {
+		CursorMut {
+			current: self.current,
+			index:   self.index,
+			list:    self,
+		}
+	}
+}
+
+impl<'a, T, A: Allocator> CursorMut<'a, T, A> {
+	/// Run a list method at this cursor's position,
+	/// returning the list's own cursor to where it was afterwards.
+	/// Any edit has to fix up the list's cursor itself.
+	fn swapped<R>(&mut self, f: impl FnOnce(&mut IterList<T, A>) -> R) -> R {
+		let current = mem::replace(&mut self.list.current, self.current);
+		let index   = mem::replace(&mut self.list.index,   self.index);
+
+		let res = f(self.list);
+
+		self.current = mem::replace(&mut self.list.current, current);
+		self.index   = mem::replace(&mut self.list.index,   index);
+		res
+	}
+
+	/// Shift the list's cursor after `count` elements were inserted at index `at`.
+	fn inserted(&mut self, at: usize, count: usize) {
+		if self.list.len == count {
+			self.list.current = self.current;
+			self.list.index   = self.index;
+		} else if self.list.index >= at {
+			self.list.index += count;
+		}
+	}
+
+	/// Shift the list's cursor after `node` at index `at` was removed.
+	fn removed(&mut self, at: usize, node: NonNull<Node<T>>) {
+		if self.list.current == node {
+			self.list.current = self.current;
+			self.list.index   = self.index;
+		} else if self.list.index > at {
+			self.list.index -= 1;
+		}
+	}
+
+	/// Get a ref to the current element. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// let cursor = list.cursor_mut();
+	///
+	/// assert_eq!(cursor.current(), Some(&1));
+	/// ```
+	#[inline]
+	pub fn current(&self) -> Option<&T> {
+		self.list.is_empty().not().then_some(unsafe { &self.current.as_ref().elem })
+	}
+
+	/// Get a mut ref to the current element. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// let mut cursor = list.cursor_mut();
+	///
+	/// *cursor.get_current_mut().unwrap() = 4;
+	/// assert_eq!(format!("{:?}", list), "[4, 2, 3]");
+	/// ```
+	#[inline]
+	pub fn get_current_mut(&mut self) -> Option<&mut T> {
+		self.list.is_empty().not().then_some(unsafe { &mut self.current.as_mut().elem })
+	}
+
+	/// Get a ref to an element at the given offset from the cursor. `O(n)`.
+	/// Returns `None` if the offset is out of bounds.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// let cursor = list.cursor_mut();
+	///
+	/// assert_eq!(cursor.get(2), Some(&3));
+	/// assert_eq!(cursor.get(-1), None);
+	/// ```
+	#[inline]
+	pub fn get(&self, offset: isize) -> Option<&T> {
+		self.index.checked_add_signed(offset)
+			.and_then(|index| self.list.node_at(index, Some((self.index, self.current))))
+			.map(|ptr| unsafe { &ptr.as_ref().elem })
+	}
+
+	/// Get a mut ref to an element at the given offset from the cursor. `O(n)`.
+	/// Returns `None` if the offset is out of bounds.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// let mut cursor = list.cursor_mut();
+	///
+	/// *cursor.get_mut(1).unwrap() = 4;
+	/// assert_eq!(format!("{:?}", list), "[1, 4, 3]");
+	/// ```
+	#[inline]
+	pub fn get_mut(&mut self, offset: isize) -> Option<&mut T> {
+		self.index.checked_add_signed(offset)
+			.and_then(|index| self.list.node_at(index, Some((self.index, self.current))))
+			.map(|mut ptr| unsafe { &mut ptr.as_mut().elem })
+	}
+
+	/// Get the index of the cursor. `O(1)`.
+	#[inline]
+	pub const fn index(&self) -> usize {
+		self.index
+	}
+
+	/// Get the number of elements in the list. `O(1)`.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.list.len
+	}
+
+	/// Check if the list is empty. `O(1)`.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.list.len == 0
+	}
+
+	/// Move the cursor one step forward. `O(1)`.
+	/// Returns `false` if the cursor could not be moved.
+	#[inline]
+	#[must_use]
+	pub fn advance(&mut self) -> bool {
+		self.swapped(|list| list.advance())
+	}
+
+	/// Move the cursor one step backward. `O(1)`.
+	/// Returns `false` if the cursor could not be moved.
+	#[inline]
+	#[must_use]
+	pub fn retreat(&mut self) -> bool {
+		self.swapped(|list| list.retreat())
+	}
+
+	/// Move the cursor by a given offset. `O(n)`.
+	/// If the offset is out of bounds the cursor will be moved to the edge,
+	/// and `false` will be returned.
+	#[inline]
+	#[must_use]
+	pub fn move_by(&mut self, offset: isize) -> bool {
+		self.swapped(|list| list.move_by(offset))
+	}
+
+	/// Move the cursor to the specified index. `O(n)`.
+	/// Walks from whichever of the front, the back or the cursor is closest.
+	/// If the index is out of bounds the cursor will be moved to the edge,
+	/// and `false` will be returned.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// let mut cursor = list.cursor_mut();
+	///
+	/// assert!(cursor.move_to(2));
+	/// assert_eq!(cursor.current(), Some(&3));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn move_to(&mut self, index: usize) -> bool {
+		self.swapped(|list| list.move_to(index))
+	}
+
+	/// Move the cursor to the front of the list. `O(1)`.
+	/// Returns the number of elements skipped over.
+	#[inline]
+	pub fn move_to_front(&mut self) -> usize {
+		self.swapped(|list| list.move_to_front())
+	}
+
+	/// Move the cursor to the back of the list. `O(1)`.
+	/// Returns the number of elements skipped over.
+	#[inline]
+	pub fn move_to_back(&mut self) -> usize {
+		self.swapped(|list| list.move_to_back())
+	}
+
+	/// Insert an element after the cursor, retaining current position. `O(1)`.
+	/// If the list is empty it will be inserted at index 0.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 3]);
+	/// let mut cursor = list.cursor_mut();
+	/// cursor.insert_next(2);
+	///
+	/// assert_eq!(cursor.current(), Some(&1));
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+	/// ```
+	pub fn insert_next(&mut self, elem: T) {
+		let at = self.index + 1;
+		self.swapped(|list| list.insert_next(elem));
+		self.inserted(at, 1);
+	}
+
+	/// Insert an element before the cursor, retaining current position. `O(1)`.
+	/// If the list is empty it will be inserted at index 0.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![2, 3]);
+	/// let mut cursor = list.cursor_mut();
+	/// cursor.insert_prev(1);
+	///
+	/// assert_eq!(cursor.current(), Some(&2));
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+	/// assert_eq!(list.current(), Some(&2));
+	/// assert_eq!(list.index(), 1);
+	/// ```
+	pub fn insert_prev(&mut self, elem: T) {
+		let at = self.index;
+		self.swapped(|list| list.insert_prev(elem));
+		self.inserted(at, 1);
+	}
+
+	/// Push an element after the cursor, moving the cursor to it. `O(1)`.
+	/// If the list is empty it will be inserted at index 0.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::new();
+	/// let mut cursor = list.cursor_mut();
+	/// cursor.push_next(1);
+	/// cursor.push_next(2);
+	///
+	/// assert_eq!(cursor.current(), Some(&2));
+	/// assert_eq!(format!("{:?}", list), "[1, 2]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	pub fn push_next(&mut self, elem: T) {
+		let at = self.index + 1;
+		self.swapped(|list| list.push_next(elem));
+		self.inserted(at, 1);
+	}
+
+	/// Push an element before the cursor, moving the cursor to it. `O(1)`.
+	/// If the list is empty it will be inserted at index 0.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![2]);
+	/// let mut cursor = list.cursor_mut();
+	/// cursor.push_prev(1);
+	///
+	/// assert_eq!(cursor.current(), Some(&1));
+	/// assert_eq!(format!("{:?}", list), "[1, 2]");
+	/// assert_eq!(list.current(), Some(&2));
+	/// ```
+	pub fn push_prev(&mut self, elem: T) {
+		let at = self.index;
+		self.swapped(|list| list.push_prev(elem));
+		self.inserted(at, 1);
+	}
+
+	/// Remove the current element and return it. `O(1)`.
+	/// The cursor will then point to the next element.
+	/// If the removed element was at the end of the list, the cursor will point to the previous
+	/// element and `false` will be returned.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// let mut cursor = list.cursor_mut();
+	///
+	/// assert_eq!(cursor.consume_forward(), Some((1, true)));
+	/// assert_eq!(cursor.current(), Some(&2));
+	/// assert_eq!(list.current(), Some(&2));
+	/// ```
+	pub fn consume_forward(&mut self) -> Option<(T, bool)> {
+		let (at, node) = (self.index, self.current);
+		let res = self.swapped(|list| list.consume_forward());
+		self.removed(at, node);
+		res
+	}
+
+	/// Remove the current element and return it. `O(1)`.
+	/// The cursor will then point to the previous element.
+	/// If the removed element was at the front of the list, the cursor will point to the next
+	/// element and `false` will be returned.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// list.move_to_back();
+	/// let mut cursor = list.cursor_mut();
+	///
+	/// cursor.move_to_front();
+	/// assert_eq!(cursor.consume_backward(), Some((1, false)));
+	/// assert_eq!(format!("{:?}", list), "[2, 3]");
+	/// assert_eq!(list.index(), 1);
+	/// ```
+	pub fn consume_backward(&mut self) -> Option<(T, bool)> {
+		let (at, node) = (self.index, self.current);
+		let res = self.swapped(|list| list.consume_backward());
+		self.removed(at, node);
+		res
+	}
+
+	/// Replace the current element with a new one. `O(1)`.
+	/// Returns the old element.
+	/// If the list is empty, the new element will be inserted, and `None` returned.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// let mut cursor = list.cursor_mut();
+	///
+	/// assert_eq!(cursor.replace_cursor(4), Some(1));
+	/// assert_eq!(format!("{:?}", list), "[4, 2, 3]");
+	/// ```
+	pub fn replace_cursor(&mut self, elem: T) -> Option<T> {
+		let at = self.index;
+		let res = self.swapped(|list| list.replace_cursor(elem));
+		if res.is_none() { self.inserted(at, 1); }
+		res
+	}
+
+	/// Split the list after the cursor. `O(1)`, plus the length of the split off part if any handles were given out.
+	/// If the list is empty, or the cursor is at the end, `None` will be returned.
+	/// If the list's cursor was in the split off part, it is moved to this cursor.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// list.move_to_back();
+	/// let mut cursor = list.cursor_mut();
+	///
+	/// cursor.move_to_front();
+	/// let new_list = cursor.split_after().unwrap();
+	///
+	/// assert_eq!(format!("{:?}", new_list), "[2, 3]");
+	/// assert_eq!(format!("{:?}", list), "[1]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	pub fn split_after(&mut self) -> Option<IterList<T, A>> where A: Clone {
+		let res = self.swapped(|list| list.split_after());
+		if self.list.index > self.index {
+			self.list.current = self.current;
+			self.list.index   = self.index;
+		}
+		res
+	}
+
+	/// Split the list before the cursor. `O(1)`, plus the length of the split off part if any handles were given out.
+	/// If the list is empty, or the cursor is at the front, `None` will be returned.
+	/// If the list's cursor was in the split off part, it is moved to this cursor.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// let mut cursor = list.cursor_mut();
+	///
+	/// cursor.move_to_back();
+	/// let new_list = cursor.split_before().unwrap();
+	///
+	/// assert_eq!(format!("{:?}", new_list), "[1, 2]");
+	/// assert_eq!(format!("{:?}", list), "[3]");
+	/// assert_eq!(list.current(), Some(&3));
+	/// ```
+	pub fn split_before(&mut self) -> Option<IterList<T, A>> where A: Clone {
+		let at  = self.index;
+		let res = self.swapped(|list| list.split_before());
+		if res.is_some() {
+			match self.list.index < at {
+				true  => { self.list.current = self.current; self.list.index = 0; },
+				false => self.list.index -= at,
+			}
+		}
+		res
+	}
+}
+
+impl<T> CursorMut<'_, T> {
+	/// Splice another list in right after the cursor. `O(1)`.
+	/// `cursor` decides where this cursor lands.
+	/// ```
+	/// # use iterlist::{IterList, Splice};
+	/// let mut list = IterList::from(vec![1, 4]);
+	/// list.move_to_back();
+	/// let mut cursor = list.cursor_mut();
+	///
+	/// cursor.move_to_front();
+	/// cursor.splice_after(IterList::from(vec![2, 3]), Splice::Last);
+	/// assert_eq!(cursor.current(), Some(&3));
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3, 4]");
+	/// assert_eq!(list.index(), 3);
+	/// ```
+	pub fn splice_after(&mut self, other: IterList<T>, cursor: Splice) {
+		let (at, count) = (self.index + 1, other.len);
+		if count == 0 { return; }
+		self.swapped(|list| list.splice_after(other, cursor));
+		self.inserted(at, count);
+	}
+
+	/// Splice another list in right before the cursor. `O(1)`.
+	/// `cursor` decides where this cursor lands.
+	/// ```
+	/// # use iterlist::{IterList, Splice};
+	/// let mut list = IterList::from(vec![3, 4]);
+	/// let mut cursor = list.cursor_mut();
+	///
+	/// cursor.splice_before(IterList::from(vec![1, 2]), Splice::Stay);
+	/// assert_eq!(cursor.index(), 2);
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3, 4]");
+	/// assert_eq!(list.current(), Some(&3));
+	/// ```
+	pub fn splice_before(&mut self, other: IterList<T>, cursor: Splice) {
+		let (at, count) = (self.index, other.len);
+		if count == 0 { return; }
+		self.swapped(|list| list.splice_before(other, cursor));
+		self.inserted(at, count);
+	}
+}
+
+impl<'a, T, A: Allocator> CursorMut<'a, T, A> {
+	/// Provides a read-only copy of this cursor. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// let mut cursor = list.cursor_mut();
+	/// cursor.advance();
+	///
+	/// let copy = cursor.as_cursor();
+	/// assert_eq!(copy.current(), Some(&2));
+	/// assert_eq!(copy.index(), 1);
+	/// ```
+	#[inline]
+	pub fn as_cursor(&self) -> Cursor<'_, T, A> {
+		self.list.cursor_at(self.current, self.index)
+	}
+
+	/// Turn this cursor into a read-only one, keeping its position. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// let mut cursor = list.cursor_mut();
+	/// cursor.move_to_back();
+	///
+	/// let mut cursor = cursor.into_cursor();
+	/// assert_eq!(cursor.current(), Some(&3));
+	/// assert!(cursor.retreat());
+	/// ```
+	#[inline]
+	pub fn into_cursor(self) -> Cursor<'a, T, A> {
+		let list: &'a IterList<T, A> = self.list;
+		list.cursor_at(self.current, self.index)
+	}
+}
+
+impl<'a, T, A: Allocator> From<CursorMut<'a, T, A>> for Cursor<'a, T, A> {
+	#[inline]
+	fn from(cursor: CursorMut<'a, T, A>) -> Self {
+		cursor.into_cursor()
+	}
+}
+
+impl<T: std::fmt::Debug, A: Allocator> std::fmt::Debug for CursorMut<'_, T, A> {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		write!(f, "{}: {:?}", self.index, self.current())
+	}
+}
@@ -0,0 +1,437 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ptr::NonNull;
+use std::ops::Not;
+
+use allocator_api2::alloc::Allocator;
+
+use super::{IterList, Node};
+
+/// A stable reference to a single element of an `IterList`.
+/// Unlike a `Cursor` it does not borrow the list, so it can be stored anywhere (eg. in a `HashMap`).
+/// It carries the id of its list and a generation, so a handle whose element has since been removed,
+/// or which belongs to another list, resolves to `None` instead of dangling.
+pub struct NodeHandle<T> {
+	node: NonNull<Node<T>>,
+	list: u64,
+	gen:  u64,
+}
+
+/// The nodes of a list that handles have been given out for, and their generations.
+/// Only allocated once the first handle is requested, so lists without handles don't pay for it.
+///
+/// It also holds order labels for every node, increasing from front to back,
+/// which let `remove` tell which side of the cursor a node is on without walking.
+/// They are built by the first `remove` and are only trusted while `labeled` matches the list's version.
+/// Inserts and removals keep them up to date, any other edit leaves them to be rebuilt.
+pub(super) struct Handles<T> {
+	next:    u64,
+	live:    HashMap<NonNull<Node<T>>, u64>,
+	labels:  HashMap<NonNull<Node<T>>, u64>,
+	labeled: Option<u64>,
+}
+
+/// How much sparser each doubling of a relabelled range has to be than the last, see `label_linked`.
+const DENSITY: f64 = 1.25;
+
+// A handle is only an id, it gives no access to the element without the list.
+unsafe impl<T> Send for NodeHandle<T> {}
+unsafe impl<T> Sync for NodeHandle<T> {}
+
+impl<T, A: Allocator> IterList<T, A> {
+	/// Get a handle to the element at the given offset from the cursor. `O(n)`, `O(1)` near the cursor.
+	/// Returns `None` if the offset is out of bounds.
+	/// To get a handle to a new element, use `insert_next_handle` and friends instead.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2]);
+	/// list.insert_next(3);
+	/// let handle = list.handle(1).unwrap();
+	///
+	/// list.move_to_back();
+	/// assert_eq!(list.get_by_handle(handle), Some(&3));
+	/// assert_eq!(list.handle(1), None);
+	/// ```
+	pub fn handle(&mut self, offset: isize) -> Option<NodeHandle<T>> {
+		self.get_raw(offset).map(|node| self.make_handle(node))
+	}
+
+	/// Get a handle to the first element. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![2, 3]);
+	/// list.push_front(1);
+	/// let handle = list.front_handle().unwrap();
+	///
+	/// assert_eq!(list.get_by_handle(handle), Some(&1));
+	/// ```
+	pub fn front_handle(&mut self) -> Option<NodeHandle<T>> {
+		self.is_empty().not().then(|| self.make_handle(self.front))
+	}
+
+	/// Get a handle to the last element. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2]);
+	/// list.push_back(3);
+	/// let handle = list.back_handle().unwrap();
+	///
+	/// assert_eq!(list.get_by_handle(handle), Some(&3));
+	/// ```
+	pub fn back_handle(&mut self) -> Option<NodeHandle<T>> {
+		self.is_empty().not().then(|| self.make_handle(self.back))
+	}
+
+	/// Same as `insert_next`, but returns a handle to the new element. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 3]);
+	/// let handle = list.insert_next_handle(2);
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+	/// assert_eq!(list.get_by_handle(handle), Some(&2));
+	/// ```
+	pub fn insert_next_handle(&mut self, elem: T) -> NodeHandle<T> {
+		let new = self.alloc_node(elem);
+		self.link_next(new);
+		self.make_handle(new)
+	}
+
+	/// Same as `insert_prev`, but returns a handle to the new element. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![2, 3]);
+	/// let handle = list.insert_prev_handle(1);
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+	/// assert_eq!(list.remove(handle), Some(1));
+	/// ```
+	pub fn insert_prev_handle(&mut self, elem: T) -> NodeHandle<T> {
+		let new = self.alloc_node(elem);
+		self.link_prev(new);
+		self.make_handle(new)
+	}
+
+	/// Same as `push_next`, but returns a handle to the new element. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 3]);
+	/// let handle = list.push_next_handle(2);
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+	/// assert_eq!(list.current(), Some(&2));
+	/// assert_eq!(list.get_by_handle(handle), Some(&2));
+	/// ```
+	pub fn push_next_handle(&mut self, elem: T) -> NodeHandle<T> {
+		self.push_next(elem);
+		self.make_handle(self.current)
+	}
+
+	/// Same as `push_prev`, but returns a handle to the new element. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![2, 3]);
+	/// let handle = list.push_prev_handle(1);
+	///
+	/// assert_eq!(list.current(), Some(&1));
+	/// assert_eq!(list.remove(handle), Some(1));
+	/// assert_eq!(format!("{:?}", list), "[2, 3]");
+	/// ```
+	pub fn push_prev_handle(&mut self, elem: T) -> NodeHandle<T> {
+		self.push_prev(elem);
+		self.make_handle(self.current)
+	}
+
+	/// Same as `push_front`, but returns a handle to the new element. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![2, 3]);
+	/// let handle = list.push_front_handle(1);
+	///
+	/// list.move_to_back();
+	/// assert_eq!(list.get_by_handle(handle), Some(&1));
+	/// ```
+	pub fn push_front_handle(&mut self, elem: T) -> NodeHandle<T> {
+		self.push_front(elem);
+		self.make_handle(self.front)
+	}
+
+	/// Same as `push_back`, but returns a handle to the new element. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2]);
+	/// let handle = list.push_back_handle(3);
+	///
+	/// assert_eq!(list.get_by_handle(handle), Some(&3));
+	/// ```
+	pub fn push_back_handle(&mut self, elem: T) -> NodeHandle<T> {
+		self.push_back(elem);
+		self.make_handle(self.back)
+	}
+
+	/// Get a ref to the element behind a handle. `O(1)`.
+	/// Returns `None` if the element has been removed, or the handle is from another list.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// let handle = list.handle(1).unwrap();
+	///
+	/// let other = IterList::from(vec![1, 2, 3]);
+	/// assert_eq!(list.get_by_handle(handle), Some(&2));
+	/// assert_eq!(other.get_by_handle(handle), None);
+	///
+	/// // a handle stays stale after the list is emptied and refilled
+	/// let mut a = IterList::from(vec![10]);
+	/// let h = a.front_handle().unwrap();
+	/// a.pop_front();
+	/// a.append(IterList::from(vec![20]));
+	/// a.push_back(30);
+	/// let back = a.back_handle().unwrap();
+	///
+	/// assert_eq!(a.get_by_handle(h), None);
+	/// assert_ne!(h, back);
+	/// ```
+	#[inline]
+	pub fn get_by_handle(&self, handle: NodeHandle<T>) -> Option<&T> {
+		self.resolve(handle).map(|node| unsafe { &(*node.as_ptr()).elem })
+	}
+
+	/// Get a mut ref to the element behind a handle. `O(1)`.
+	/// Returns `None` if the element has been removed, or the handle is from another list.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// let handle = list.back_handle().unwrap();
+	///
+	/// *list.get_by_handle_mut(handle).unwrap() = 4;
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 4]");
+	/// ```
+	#[inline]
+	pub fn get_by_handle_mut(&mut self, handle: NodeHandle<T>) -> Option<&mut T> {
+		self.resolve(handle).map(|node| unsafe { &mut (*node.as_ptr()).elem })
+	}
+
+	/// Remove the element behind a handle. `O(1)`.  
+	/// To keep the cursor's index right, the list keeps order labels for its nodes once this is first called.
+	/// Inserting and removing elements keeps them up to date, in amortized `O(log n)` for inserts,
+	/// but after any other edit (`splice_after`, `sort`, `reverse`, ...) the next call rebuilds them in `O(n)`.
+	/// If the element is the current one, this acts like `consume_forward`.
+	/// Returns `None` if the element has already been removed, or the handle is from another list.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3, 4]);
+	/// let handle = list.handle(1).unwrap();
+	/// list.move_to_back();
+	///
+	/// assert_eq!(list.remove(handle), Some(2));
+	/// assert_eq!(list.remove(handle), None);
+	/// assert_eq!(format!("{:?}", list), "[1, 3, 4]");
+	/// assert_eq!(list.current(), Some(&4));
+	/// assert_eq!(list.index(), 2);
+	/// ```
+	pub fn remove(&mut self, handle: NodeHandle<T>) -> Option<T> {
+		let node = self.resolve(handle)?;
+		if node == self.current {
+			let fresh = self.labels_fresh();
+			let elem  = self.consume_forward().map(|(elem, _)| elem);
+			self.keep_labels(fresh);
+			return elem;
+		}
+
+		if !self.labels_fresh() { self.relabel(); }
+		if self.label(node) < self.label(self.current) { self.index -= 1; }
+
+		let elem = unsafe { self.unlink(node) }.elem;
+		self.keep_labels(true);
+		Some(elem)
+	}
+
+	/// Move the cursor onto the element behind a handle.
+	/// Walks to the nearest of the cursor, the front or the back to find its index.
+	/// Returns `false` and leaves the cursor alone if the handle is stale or from another list.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// let handle = list.back_handle().unwrap();
+	///
+	/// assert!(list.move_cursor_to(handle));
+	/// assert_eq!(list.current(), Some(&3));
+	/// assert_eq!(list.index(), 2);
+	/// ```
+	pub fn move_cursor_to(&mut self, handle: NodeHandle<T>) -> bool {
+		self.resolve(handle).map(|node| {
+			self.index   = self.index_of(node);
+			self.current = node;
+		}).is_some()
+	}
+
+	/// Check a handle against this list, returning its node if it's still alive.
+	fn resolve(&self, handle: NodeHandle<T>) -> Option<NonNull<Node<T>>> {
+		self.handles.as_ref()
+			.filter(|_| handle.list == self.id())
+			.and_then(|h| h.live.get(&handle.node))
+			.filter(|&&gen| gen == handle.gen)
+			.map(|_| handle.node)
+	}
+
+	fn make_handle(&mut self, node: NonNull<Node<T>>) -> NodeHandle<T> {
+		let list = self.id();
+		let handles = self.handles.get_or_insert_with(|| Box::new(Handles {
+			next:    0,
+			live:    HashMap::new(),
+			labels:  HashMap::new(),
+			labeled: None,
+		}));
+
+		let gen = *handles.live.entry(node).or_insert_with(|| {
+			handles.next += 1;
+			handles.next
+		});
+
+		NodeHandle { node, list, gen }
+	}
+
+	/// Invalidate the handles of a node that is leaving this list. `O(1)`.
+	#[inline]
+	pub(super) fn forget_node(&mut self, node: NonNull<Node<T>>) {
+		if let Some(handles) = self.handles.as_mut() {
+			handles.live.remove(&node);
+			handles.labels.remove(&node);
+		}
+	}
+
+	/// Whether the order labels still match the list.
+	#[inline]
+	pub(super) fn labels_fresh(&self) -> bool {
+		self.handles.as_ref().is_some_and(|h| h.labeled == Some(self.version))
+	}
+
+	/// Mark the order labels as still matching the list after a node was removed,
+	/// if they did before, as the other nodes stay in order.
+	#[inline]
+	fn keep_labels(&mut self, fresh: bool) {
+		let version = self.version;
+		if let Some(handles) = self.handles.as_mut().filter(|_| fresh) {
+			handles.labeled = Some(version);
+		}
+	}
+
+	/// The order label of a node. The labels must be fresh.
+	#[inline]
+	fn label(&self, node: NonNull<Node<T>>) -> u64 {
+		unsafe { self.handles.as_ref().unwrap_unchecked() }.labels[&node]
+	}
+
+	/// Spread the order labels evenly over every node, front to back. `O(n)`.
+	fn relabel(&mut self) {
+		let (front, len, version) = (self.front, self.len, self.version);
+		let Some(handles) = self.handles.as_mut() else { return };
+
+		handles.labels.clear();
+		let step = (1u128 << 64) / (len as u128 + 1);
+		let mut node = (len != 0).then_some(front);
+		for i in 1.. {
+			let Some(n) = node else { break };
+			handles.labels.insert(n, (step * i) as u64);
+			node = unsafe { n.as_ref() }.next;
+		}
+		handles.labeled = Some(version);
+	}
+
+	/// Label a node that was just linked in, if the labels were fresh before it was. Amortized `O(log n)`.  
+	/// It gets the middle of the gap between its neighbours. If there is none, the smallest aligned
+	/// range of labels around it that is sparse enough is spread out evenly over the nodes in it.
+	pub(super) fn label_linked(&mut self, node: NonNull<Node<T>>, fresh: bool) {
+		if !fresh { return; }
+		let version = self.version;
+		let handles = unsafe { self.handles.as_mut().unwrap_unchecked() };
+
+		let (prev, next) = unsafe { (node.as_ref().prev, node.as_ref().next) };
+		let lo = prev.map_or(0, |p| handles.labels[&p]);
+		let hi = next.map_or(u64::MAX, |n| handles.labels[&n]);
+
+		if hi - lo >= 2 {
+			handles.labels.insert(node, lo + (hi - lo) / 2);
+			handles.labeled = Some(version);
+			return;
+		}
+
+		for bits in 1..=64 {
+			let size = 1u128 << bits;
+			let base = lo as u128 & !(size - 1);
+			let in_range = |label: u64| (base..base + size).contains(&(label as u128));
+
+			let mut nodes = Vec::new();
+			let mut at = prev;
+			while let Some(n) = at.filter(|n| in_range(handles.labels[n])) {
+				nodes.push(n);
+				at = unsafe { n.as_ref() }.prev;
+			}
+			nodes.reverse();
+			nodes.push(node);
+
+			let mut at = next;
+			while let Some(n) = at.filter(|n| in_range(handles.labels[n])) {
+				nodes.push(n);
+				at = unsafe { n.as_ref() }.next;
+			}
+
+			if nodes.len() as f64 > (2.0 / DENSITY).powi(bits) { continue; }
+
+			// label 0 is the front's lower bound, so the range is split in `len + 1` and its start skipped
+			let step = size / (nodes.len() as u128 + 1);
+			for (i, n) in (1..).zip(nodes) {
+				handles.labels.insert(n, (base + step * i) as u64);
+			}
+			handles.labeled = Some(version);
+			return;
+		}
+	}
+
+	/// Invalidate the handles of `count` nodes starting at `node`, which are leaving this list.
+	/// `O(count)` if any handles were given out, `O(1)` otherwise.
+	pub(super) fn forget_nodes(&mut self, mut node: NonNull<Node<T>>, count: usize) {
+		let Some(handles) = self.handles.as_mut() else { return };
+		if handles.live.is_empty() { return; }
+
+		for _ in 0..count {
+			handles.live.remove(&node);
+			match unsafe { node.as_ref() }.next {
+				Some(next) => node = next,
+				None => break,
+			}
+		}
+	}
+}
+
+impl<T> Clone for NodeHandle<T> {
+	#[inline]
+	fn clone(&self) -> Self { *self }
+}
+
+impl<T> Copy for NodeHandle<T> {}
+
+impl<T> PartialEq for NodeHandle<T> {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		(self.node, self.list, self.gen) == (other.node, other.list, other.gen)
+	}
+}
+
+impl<T> Eq for NodeHandle<T> {}
+
+impl<T> Hash for NodeHandle<T> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		(self.node, self.list, self.gen).hash(state);
+	}
+}
+
+impl<T> fmt::Debug for NodeHandle<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("NodeHandle")
+			.field("list", &self.list)
+			.field("gen", &self.gen)
+			.finish()
+	}
+}
@@ -0,0 +1,307 @@
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+use std::ops::Not;
+
+use allocator_api2::alloc::{Allocator, Global};
+
+use super::{IterList, Node};
+
+/// A borrowing iterator over the whole list, front to back. Made by `IterList::iter`.
+pub struct Iter<'a, T> {
+	front: NonNull<Node<T>>,
+	back:  NonNull<Node<T>>,
+	len:   usize,
+	_boo:  PhantomData<&'a T>,
+}
+
+/// A mutably borrowing iterator over the whole list, front to back. Made by `IterList::iter_mut`.
+pub struct IterMut<'a, T> {
+	front: NonNull<Node<T>>,
+	back:  NonNull<Node<T>>,
+	len:   usize,
+	_boo:  PhantomData<&'a mut T>,
+}
+
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+impl<T, A: Allocator> IterList<T, A> {
+	/// Iterate over the whole list from front to back, wherever the cursor is. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// list.move_to_back();
+	///
+	/// assert_eq!(list.iter().collect::<Vec<_>>(), [&1, &2, &3]);
+	/// assert_eq!(list.iter().rev().next(), Some(&3));
+	/// assert_eq!(list.iter().len(), 3);
+	/// ```
+	#[inline]
+	pub fn iter(&self) -> Iter<'_, T> {
+		Iter { front: self.front, back: self.back, len: self.len, _boo: PhantomData }
+	}
+
+	/// Iterate mutably over the whole list from front to back, wherever the cursor is. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// list.advance();
+	///
+	/// list.iter_mut().for_each(|elem| *elem *= 2);
+	/// assert_eq!(format!("{:?}", list), "[2, 4, 6]");
+	/// assert_eq!(list.current(), Some(&4));
+	/// ```
+	#[inline]
+	pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+		IterMut { front: self.front, back: self.back, len: self.len, _boo: PhantomData }
+	}
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+	type Item = &'a T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.len == 0 { return None; }
+		self.len -= 1;
+
+		let node = unsafe { &*self.front.as_ptr() };
+		if self.len != 0 { self.front = unsafe { node.next.unwrap_unchecked() }; }
+		Some(&node.elem)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>)
+		{ (self.len, Some(self.len)) }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.len == 0 { return None; }
+		self.len -= 1;
+
+		let node = unsafe { &*self.back.as_ptr() };
+		if self.len != 0 { self.back = unsafe { node.prev.unwrap_unchecked() }; }
+		Some(&node.elem)
+	}
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+	#[inline]
+	fn clone(&self) -> Self {
+		Self { ..*self }
+	}
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+	type Item = &'a mut T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.len == 0 { return None; }
+		self.len -= 1;
+
+		let node = unsafe { &mut *self.front.as_ptr() };
+		if self.len != 0 { self.front = unsafe { node.next.unwrap_unchecked() }; }
+		Some(&mut node.elem)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>)
+		{ (self.len, Some(self.len)) }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.len == 0 { return None; }
+		self.len -= 1;
+
+		let node = unsafe { &mut *self.back.as_ptr() };
+		if self.len != 0 { self.back = unsafe { node.prev.unwrap_unchecked() }; }
+		Some(&mut node.elem)
+	}
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a IterList<T, A> {
+	type Item     = &'a T;
+	type IntoIter = Iter<'a, T>;
+
+	/// Same as `IterList::iter`. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	///
+	/// let mut sum = 0;
+	/// for elem in &list { sum += elem; }
+	/// assert_eq!(sum, 6);
+	/// ```
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a mut IterList<T, A> {
+	type Item     = &'a mut T;
+	type IntoIter = IterMut<'a, T>;
+
+	/// Same as `IterList::iter_mut`. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	///
+	/// for elem in &mut list { *elem += 1; }
+	/// assert_eq!(format!("{:?}", list), "[2, 3, 4]");
+	/// ```
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter_mut()
+	}
+}
+
+/// An owning iterator over the list, front to back. Made by `IterList::into_iter`.  
+/// Elements that aren't yielded are dropped along with it.
+pub struct IntoIter<T, A: Allocator = Global> {
+	list: IterList<T, A>,
+}
+
+impl<T, A: Allocator> IntoIterator for IterList<T, A> {
+	type Item     = T;
+	type IntoIter = IntoIter<T, A>;
+
+	/// Turn the list into an iterator over its elements, front to back, wherever the cursor is. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3, 4]);
+	/// list.move_to_back();
+	///
+	/// let mut iter = list.into_iter();
+	/// assert_eq!(iter.len(), 4);
+	/// assert_eq!(iter.next_back(), Some(4));
+	/// assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3]);
+	/// ```
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		IntoIter { list: self }
+	}
+}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+	type Item = T;
+
+	/// Internally this call is just `pop_front`. `O(1)`.
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.list.pop_front()
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>)
+		{ (self.list.len, Some(self.list.len)) }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
+	/// Internally this call is just `pop_back`. `O(1)`.
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.list.pop_back()
+	}
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
+impl<T, A: Allocator> FusedIterator for IntoIter<T, A> {}
+
+/// An iterator over a range removed from the list. Made by `IterList::drain` and `IterList::drain_relative`.  
+/// The range is already unlinked, so the list stays consistent even if this is leaked.
+/// Elements that aren't yielded are dropped along with it.
+pub struct Drain<'a, T, A: Allocator = Global> {
+	iter: IntoIter<T, A>,
+	_boo: PhantomData<&'a mut IterList<T, A>>,
+}
+
+impl<T, A: Allocator> Drain<'_, T, A> {
+	#[inline]
+	pub(super) fn new(list: IterList<T, A>) -> Self {
+		Self { iter: list.into_iter(), _boo: PhantomData }
+	}
+}
+
+impl<T, A: Allocator> Iterator for Drain<'_, T, A> {
+	type Item = T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.iter.next()
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>)
+		{ self.iter.size_hint() }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for Drain<'_, T, A> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.iter.next_back()
+	}
+}
+
+impl<T, A: Allocator> ExactSizeIterator for Drain<'_, T, A> {}
+impl<T, A: Allocator> FusedIterator for Drain<'_, T, A> {}
+
+/// A lazy iterator removing the elements that match a predicate. Made by `IterList::extract_if`.  
+/// Elements are only looked at as it's advanced, dropping it early keeps the rest in the list.
+pub struct ExtractIf<'a, T, F: FnMut(&mut T) -> bool, A: Allocator = Global> {
+	list: &'a mut IterList<T, A>,
+	next: Option<NonNull<Node<T>>>,
+	pos:  usize,
+	pred: F,
+}
+
+impl<'a, T, F: FnMut(&mut T) -> bool, A: Allocator> ExtractIf<'a, T, F, A> {
+	#[inline]
+	pub(super) fn new(list: &'a mut IterList<T, A>, pred: F) -> Self {
+		let next = list.is_empty().not().then_some(list.front);
+		Self { list, next, pos: 0, pred }
+	}
+}
+
+impl<T, F: FnMut(&mut T) -> bool, A: Allocator> Iterator for ExtractIf<'_, T, F, A> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while let Some(mut node) = self.next {
+			self.next = unsafe { node.as_ref() }.next;
+
+			if !(self.pred)(unsafe { &mut node.as_mut().elem }) {
+				self.pos += 1;
+				continue;
+			}
+
+			if node == self.list.current {
+				return self.list.consume_forward().map(|(elem, _)| elem);
+			}
+
+			if self.pos < self.list.index { self.list.index -= 1; }
+			return Some(unsafe { self.list.unlink(node) }.elem);
+		}
+		None
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>)
+		{ (0, Some(self.list.len - self.pos)) }
+}
+
+impl<T, F: FnMut(&mut T) -> bool, A: Allocator> FusedIterator for ExtractIf<'_, T, F, A> {}
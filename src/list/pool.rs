@@ -0,0 +1,278 @@
+use std::alloc::{Layout, handle_alloc_error};
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::ptr::NonNull;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use allocator_api2::alloc::{AllocError, Allocator, Global};
+
+use super::{IterList, Node};
+
+/// Spare node allocations, waiting to be reused.
+type Free<T> = Vec<Box<MaybeUninit<Node<T>>>>;
+
+/// A stash of node allocations that lists give back to instead of freeing, and take from instead of allocating.
+/// Handy when elements are removed and inserted all the time, say a lexer consuming its tokens as it goes.
+///
+/// A pool is cheap to clone, the clones share the same nodes, so several lists can recycle through one pool.
+/// A pool held by a single list is used without locking, a shared one takes a short lock per node.
+/// Nodes are plain boxes either way, so they can still be moved between lists with and without a pool.
+/// ```
+/// # use iterlist::{IterList, NodePool};
+/// let pool = NodePool::with_capacity(2);
+/// let mut pending = IterList::with_pool(pool.clone());
+/// let mut done    = IterList::with_pool(pool.clone());
+///
+/// pending.push_next(1);
+/// pending.push_next(2);
+/// assert_eq!(pool.len(), 0);
+///
+/// let (token, _) = pending.consume_forward().unwrap();
+/// assert_eq!(pool.len(), 1);
+///
+/// done.push_next(token);
+/// assert_eq!(pool.len(), 0);
+/// ```
+pub struct NodePool<T> {
+	free: Arc<Mutex<Free<T>>>,
+}
+
+// The pool only holds uninitialized memory, there are no elements in it to send or share.
+unsafe impl<T> Send for NodePool<T> {}
+unsafe impl<T> Sync for NodePool<T> {}
+
+impl<T> NodePool<T> {
+	/// Create a new empty pool. `O(1)`.
+	/// ```
+	/// # use iterlist::NodePool;
+	/// let pool: NodePool<u8> = NodePool::new();
+	/// assert!(pool.is_empty());
+	/// ```
+	#[inline]
+	pub fn new() -> Self {
+		Self { free: Arc::default() }
+	}
+
+	/// Create a new pool with `count` nodes ready to go. `O(count)`.
+	/// ```
+	/// # use iterlist::NodePool;
+	/// let pool: NodePool<u8> = NodePool::with_capacity(4);
+	/// assert_eq!(pool.len(), 4);
+	/// ```
+	#[inline]
+	pub fn with_capacity(count: usize) -> Self {
+		let pool = Self::new();
+		pool.reserve_spare(count);
+		pool
+	}
+
+	/// Get the number of spare nodes in the pool. `O(1)`.
+	/// ```
+	/// # use iterlist::{IterList, NodePool};
+	/// let mut list = IterList::with_pool(NodePool::new());
+	/// list.push_next(1);
+	/// list.pop_back();
+	///
+	/// assert_eq!(list.pool().unwrap().len(), 1);
+	/// ```
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.lock().len()
+	}
+
+	/// Check if the pool has no spare nodes. `O(1)`.
+	/// ```
+	/// # use iterlist::NodePool;
+	/// let pool: NodePool<u8> = NodePool::new();
+	/// assert!(pool.is_empty());
+	/// ```
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.lock().is_empty()
+	}
+
+	/// Allocate nodes until there are at least `count` spare ones. `O(count)`.
+	/// Unlike `Vec::reserve`, `count` is the total to have on hand, not how many to add.
+	/// ```
+	/// # use iterlist::NodePool;
+	/// let pool: NodePool<u8> = NodePool::with_capacity(2);
+	/// pool.reserve_spare(5);
+	/// assert_eq!(pool.len(), 5);
+	///
+	/// pool.reserve_spare(3);
+	/// assert_eq!(pool.len(), 5);
+	/// ```
+	pub fn reserve_spare(&self, count: usize) {
+		let mut free = self.lock();
+		let missing = count.saturating_sub(free.len());
+		free.reserve(missing);
+		free.extend((0..missing).map(|_| Box::new(MaybeUninit::uninit())));
+	}
+
+	/// Free all the spare nodes. `O(n)`.
+	/// Nodes still in use by lists come back to the pool as usual once they're removed.
+	/// ```
+	/// # use iterlist::NodePool;
+	/// let pool: NodePool<u8> = NodePool::with_capacity(8);
+	/// pool.shrink_to_fit();
+	/// assert!(pool.is_empty());
+	/// ```
+	pub fn shrink_to_fit(&self) {
+		let mut free = self.lock();
+		free.clear();
+		free.shrink_to_fit();
+	}
+
+	/// Check if any other list or pool shares these nodes.
+	#[inline]
+	pub(super) fn is_shared(&self) -> bool {
+		Arc::strong_count(&self.free) > 1
+	}
+
+	/// Put an element into a spare node, or a new one if there are none. `O(1)`.
+	pub(super) fn try_alloc(&mut self, elem: T) -> Result<NonNull<Node<T>>, (T, AllocError)> {
+		match self.with(Vec::pop) {
+			Some(slot) => Ok(NonNull::from(Box::leak(slot).write(Node { next: None, prev: None, elem }))),
+			None => Node::try_new_in(elem, &Global),
+		}
+	}
+
+	/// Take a node back into the pool. `O(1)`, amortized if the pool grows.
+	///
+	/// # Safety
+	/// `node` must not be linked into any list, and its element must have been moved out already.
+	#[inline]
+	pub(super) unsafe fn recycle(&mut self, node: NonNull<Node<T>>) {
+		let slot = Box::from_raw(node.as_ptr().cast::<MaybeUninit<Node<T>>>());
+		self.with(|free| free.push(slot));
+	}
+
+	/// Get at the spare nodes, skipping the lock if no one else can see them.
+	#[inline]
+	fn with<R>(&mut self, f: impl FnOnce(&mut Free<T>) -> R) -> R {
+		match Arc::get_mut(&mut self.free) {
+			Some(free) => f(free.get_mut().unwrap_or_else(PoisonError::into_inner)),
+			None => f(&mut self.lock()),
+		}
+	}
+
+	#[inline]
+	fn lock(&self) -> std::sync::MutexGuard<'_, Free<T>> {
+		self.free.lock().unwrap_or_else(PoisonError::into_inner)
+	}
+}
+
+impl<T> IterList<T> {
+	/// Create a new empty list that takes its nodes from `pool`, and gives them back to it. `O(1)`.
+	/// Lists split off from this one share the pool too.
+	/// ```
+	/// # use iterlist::{IterList, NodePool};
+	/// let pool = NodePool::with_capacity(3);
+	/// let mut list = IterList::with_pool(pool.clone());
+	///
+	/// for elem in [1, 2, 3] { list.push_next(elem); }
+	/// assert!(pool.is_empty());
+	///
+	/// drop(list);
+	/// assert_eq!(pool.len(), 3);
+	/// ```
+	#[inline]
+	pub fn with_pool(pool: NodePool<T>) -> Self {
+		let mut list = Self::new();
+		list.pool = Some(pool);
+		list
+	}
+
+	/// Create a new empty list with a pool of its own, holding `count` nodes. `O(count)`.
+	/// The first `count` insertions won't allocate, and removed nodes are kept around for later.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::with_capacity(2);
+	/// list.push_next(1);
+	/// list.push_next(2);
+	///
+	/// assert_eq!(list.pool().map(|pool| pool.len()), Some(0));
+	/// list.consume_forward();
+	/// assert_eq!(list.pool().map(|pool| pool.len()), Some(1));
+	/// ```
+	#[inline]
+	pub fn with_capacity(count: usize) -> Self {
+		Self::with_pool(NodePool::with_capacity(count))
+	}
+}
+
+impl<T, A: Allocator> IterList<T, A> {
+	/// Get the pool this list recycles its nodes through, if it has one. `O(1)`.
+	/// Clone it to share it with another list.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let list = IterList::<u8>::with_capacity(4);
+	/// let other = IterList::<u8>::with_pool(list.pool().unwrap().clone());
+	///
+	/// assert_eq!(other.pool().unwrap().len(), 4);
+	/// assert!(IterList::<u8>::new().pool().is_none());
+	/// ```
+	#[inline]
+	pub fn pool(&self) -> Option<&NodePool<T>> {
+		self.pool.as_ref()
+	}
+
+	/// Take a new node for an element, from the pool if there is one, or the list's allocator. `O(1)`.
+	/// Only lists on the global allocator can have a pool.
+	#[inline]
+	pub(super) fn alloc_node(&mut self, elem: T) -> NonNull<Node<T>> {
+		self.try_alloc_node(elem).unwrap_or_else(|_| handle_alloc_error(Layout::new::<Node<T>>()))
+	}
+
+	/// Same as `alloc_node`, handing the element back if the allocator fails. `O(1)`.
+	#[inline]
+	pub(super) fn try_alloc_node(&mut self, elem: T) -> Result<NonNull<Node<T>>, (T, AllocError)> {
+		match self.pool.as_mut() {
+			Some(pool) => pool.try_alloc(elem),
+			None => Node::try_new_in(elem, &self.alloc),
+		}
+	}
+
+	/// Move a node out of its allocation, and give it back to the pool or the list's allocator. `O(1)`.
+	///
+	/// # Safety
+	/// `node` must not be linked into any list anymore.
+	#[inline]
+	pub(super) unsafe fn free_node(&mut self, node: NonNull<Node<T>>) -> Node<T> {
+		match self.pool.as_mut() {
+			Some(pool) => {
+				let taken = node.as_ptr().read();
+				pool.recycle(node);
+				taken
+			},
+			None => {
+				let taken = node.as_ptr().read();
+				self.alloc.deallocate(node.cast(), Layout::new::<Node<T>>());
+				taken
+			},
+		}
+	}
+}
+
+impl<T> Clone for NodePool<T> {
+	/// Get another handle to the same pool. `O(1)`.
+	#[inline]
+	fn clone(&self) -> Self {
+		Self { free: Arc::clone(&self.free) }
+	}
+}
+
+impl<T> Default for NodePool<T> {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T> fmt::Debug for NodePool<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("NodePool")
+			.field("len", &self.len())
+			.finish()
+	}
+}
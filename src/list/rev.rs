@@ -0,0 +1,247 @@
+use std::cmp::Ordering;
+use std::fmt::Debug;
+
+use allocator_api2::alloc::{Allocator, Global};
+
+use super::{IterList, Cursor};
+
+/// A read-only cursor that sees the list back to front. Made by `IterList::as_rev_cursor`.
+/// It's just a `Cursor` with every direction flipped, so the list itself isn't touched:
+/// advancing walks towards the front, and index `0` is the back of the list.
+/// ```
+/// # use iterlist::IterList;
+/// let list = IterList::from(vec![1, 2, 3]);
+/// let mut rev = list.as_rev_cursor();
+///
+/// assert_eq!(rev.index(), 2);
+/// assert_eq!(rev.move_to_front(), 2);
+/// assert_eq!(rev.collect::<Vec<_>>(), [&3, &2, &1]);
+/// ```
+#[derive(Clone, Copy)]
+pub struct Rev<'i, T, A: Allocator = Global> {
+	cursor: Cursor<'i, T, A>,
+}
+
+impl<T, A: Allocator> IterList<T, A> {
+	/// Provides a copy of the current cursor that walks the list backwards. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// list.advance();
+	///
+	/// let mut rev = list.as_rev_cursor();
+	/// assert_eq!(rev.current(), Some(&2));
+	/// assert_eq!(rev.index(), 1);
+	/// assert!(rev.advance());
+	/// assert_eq!(rev.current(), Some(&1));
+	/// ```
+	#[inline]
+	pub fn as_rev_cursor(&self) -> Rev<'_, T, A> {
+		Rev { cursor: self.as_cursor() }
+	}
+}
+
+impl<'i, T, A: Allocator> Rev<'i, T, A> {
+	/// Flip the view back around, into a normal cursor on the same element. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// let mut rev = list.as_rev_cursor();
+	/// rev.move_to_front();
+	///
+	/// let cursor = rev.rev();
+	/// assert_eq!(cursor.current(), Some(&3));
+	/// assert_eq!(cursor.index(), 2);
+	/// ```
+	#[inline]
+	pub fn rev(self) -> Cursor<'i, T, A> {
+		self.cursor
+	}
+
+	#[inline]
+	fn len(&self) -> usize {
+		self.cursor.list.map_or(0, |list| list.len)
+	}
+
+	/// Get a ref to the current element. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// let rev = list.as_rev_cursor();
+	///
+	/// assert_eq!(rev.current(), Some(&1));
+	/// ```
+	#[inline]
+	pub fn current(&self) -> Option<&T> {
+		self.cursor.current()
+	}
+
+	/// Get the index of the cursor, counted from the back of the list. `O(1)`.
+	/// Once iterated past the front of the list this is `len`, like a `Cursor` past the back.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// let mut rev = list.as_rev_cursor();
+	/// assert_eq!(rev.index(), 2);
+	///
+	/// rev.next();
+	/// assert_eq!(rev.current(), None);
+	/// assert_eq!(rev.index(), 3);
+	/// ```
+	#[inline]
+	pub fn index(&self) -> usize {
+		match (self.len(), self.cursor.node()) {
+			(0, _)         => 0,
+			(len, None)    => len,
+			(len, Some(_)) => len - 1 - self.cursor.index,
+		}
+	}
+
+	/// Move to the front of the view, which is the back of the list. `O(1)`.
+	/// Returns the number of elements skipped over.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// let mut rev = list.as_rev_cursor();
+	///
+	/// assert_eq!(rev.move_to_front(), 2);
+	/// assert_eq!(rev.current(), Some(&3));
+	/// assert_eq!(rev.index(), 0);
+	/// ```
+	#[inline]
+	pub fn move_to_front(&mut self) -> usize {
+		self.cursor.move_to_back()
+	}
+
+	/// Move to the back of the view, which is the front of the list. `O(1)`.
+	/// Returns the number of elements skipped over.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// list.move_to_back();
+	/// let mut rev = list.as_rev_cursor();
+	///
+	/// assert_eq!(rev.move_to_back(), 2);
+	/// assert_eq!(rev.current(), Some(&1));
+	/// ```
+	#[inline]
+	pub fn move_to_back(&mut self) -> usize {
+		self.cursor.move_to_front()
+	}
+
+	/// Move the cursor to the specified index, counted from the back of the list. `O(n)`.
+	/// If the index is out of bounds the cursor will be moved to the back of the view,
+	/// and `false` will be returned.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// let mut rev = list.as_rev_cursor();
+	///
+	/// assert!(rev.move_to(0));
+	/// assert_eq!(rev.current(), Some(&3));
+	/// assert!(!rev.move_to(5));
+	/// assert_eq!(rev.current(), Some(&1));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn move_to(&mut self, index: usize) -> bool {
+		match self.len().checked_sub(index).and_then(|i| i.checked_sub(1)) {
+			Some(index) => self.cursor.move_to(index),
+			None => { self.cursor.move_to_front(); false },
+		}
+	}
+
+	/// Move one step forward in the view, towards the front of the list. `O(1)`.
+	/// Returns `false` if the cursor could not be moved.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// let mut rev = list.as_rev_cursor();
+	///
+	/// assert!(!rev.advance());
+	/// rev.move_to_front();
+	/// assert!(rev.advance());
+	/// assert_eq!(rev.current(), Some(&2));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn advance(&mut self) -> bool {
+		self.cursor.retreat()
+	}
+
+	/// Move one step backward in the view, towards the back of the list. `O(1)`.
+	/// Returns `false` if the cursor could not be moved.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// let mut rev = list.as_rev_cursor();
+	///
+	/// assert!(rev.retreat());
+	/// assert_eq!(rev.current(), Some(&2));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn retreat(&mut self) -> bool {
+		self.cursor.advance()
+	}
+
+	/// Move the cursor by a given offset in the view. `O(n)`.
+	/// If the offset is out of bounds the cursor will be moved to the edge,
+	/// and `false` will be returned.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// let mut rev = list.as_rev_cursor();
+	///
+	/// rev.move_by(-2);
+	/// assert_eq!(rev.current(), Some(&3));
+	/// assert_eq!(rev.index(), 0);
+	///
+	/// assert!(!rev.move_by(isize::MIN));
+	/// assert_eq!(rev.current(), Some(&3));
+	/// ```
+	#[inline]
+	pub fn move_by(&mut self, offset: isize) -> bool {
+		match offset.cmp(&0) {
+			Ordering::Greater => !(0..offset.unsigned_abs()).any(|_| !self.advance()),
+			Ordering::Less    => !(0..offset.unsigned_abs()).any(|_| !self.retreat()),
+			Ordering::Equal   => true,
+		}
+	}
+
+	/// Get a ref to an element at the given offset in the view. `O(n)`.
+	/// Returns `None` if the offset is out of bounds.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// let mut rev = list.as_rev_cursor();
+	/// rev.move_to_front();
+	///
+	/// assert_eq!(rev.get(1), Some(&2));
+	/// assert_eq!(rev.get(-1), None);
+	/// assert_eq!(rev.get(isize::MIN), None);
+	/// ```
+	#[inline]
+	pub fn get(&self, offset: isize) -> Option<&T> {
+		self.cursor.get(offset.checked_neg()?)
+	}
+}
+
+impl<'i, T, A: Allocator> Iterator for Rev<'i, T, A> {
+	type Item = &'i T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.cursor.node().map(|c| {
+			self.cursor.current = unsafe { c.as_ref().prev };
+			self.cursor.index   = self.cursor.index.saturating_sub(1);
+			unsafe { &c.as_ref().elem }
+		})
+	}
+}
+
+impl<T: Debug, A: Allocator> Debug for Rev<'_, T, A> {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		write!(f, "{}: {:?}", self.index(), self.current())
+	}
+}
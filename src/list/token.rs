@@ -0,0 +1,383 @@
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+use std::ops::Not;
+
+use allocator_api2::alloc::{Allocator, Global};
+
+use super::{IterList, CursorMut, Node, Splice};
+
+/// Invariant lifetime, so cursors can only ever be used with the token of their own scope.
+type Brand<'id> = PhantomData<fn(&'id ()) -> &'id ()>;
+
+/// A cursor position, shared with the token so other cursors' edits can shift it.
+type Pos<T> = Cell<(NonNull<Node<T>>, usize)>;
+
+/// Every cursor handed out in a scope, dead ones are pruned lazily.
+type Registry<T> = Rc<RefCell<Vec<Weak<Pos<T>>>>>;
+
+/// The permission to access a list through any number of `TokenCursor`s.
+/// Obtained from `IterList::with_token`.
+///
+/// Much like a `GhostCell` token, it's branded with a unique lifetime,
+/// so cursors of one scope can't be used with the token of another.
+/// Reading through a cursor borrows the token, editing borrows it mutably,
+/// so cursors can stay alive on the same list while any of them edits.
+///
+/// Indices of every cursor (and of the list's own cursor) are kept up to date through edits.
+/// Removing an element that another cursor is still on is caught and refused.
+/// ```compile_fail
+/// # use iterlist::IterList;
+/// let mut a = IterList::from(vec![1, 2, 3]);
+/// let mut b = IterList::from(vec![4, 5, 6]);
+///
+/// a.with_token(|token_a| b.with_token(|token_b| {
+///     let cursor = token_a.cursor();
+///     cursor.current(&token_b); // wrong token
+/// }));
+/// ```
+pub struct ListToken<'id, 'a, T, A: Allocator = Global> {
+	list:    &'a mut IterList<T, A>,
+	cursors: Registry<T>,
+	_brand:  Brand<'id>,
+}
+
+/// A cursor into a list, usable only together with its `ListToken`.
+/// Cloning it creates a new, independent cursor at the same position.
+/// ```
+/// # use iterlist::IterList;
+/// let mut list = IterList::from(vec![1, 2, 3]);
+///
+/// list.with_token(|mut token| {
+///     let mut a = token.cursor();
+///     let mut b = a.clone();
+///     b.move_to_back(&token);
+///
+///     a.insert_prev(&mut token, 0);
+///     b.push_next(&mut token, 4);
+///
+///     assert_eq!(a.current(&token), Some(&1));
+///     assert_eq!(a.index(), 1);
+///     assert_eq!(b.current(&token), Some(&4));
+///     assert_eq!(b.index(), 4);
+/// });
+///
+/// assert_eq!(format!("{:?}", list), "[0, 1, 2, 3, 4]");
+/// ```
+pub struct TokenCursor<'id, T, A: Allocator = Global> {
+	pos:     Rc<Pos<T>>,
+	cursors: Registry<T>,
+	_brand:  Brand<'id>,
+	_alloc:  PhantomData<A>,
+}
+
+impl<T, A: Allocator> IterList<T, A> {
+	/// Borrow the list behind a `ListToken` for the duration of `f`. `O(1)`.
+	/// Inside, any number of `TokenCursor`s can be created and used to read or edit the list.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	///
+	/// let sum = list.with_token(|token| {
+	///     let mut cursor = token.cursor();
+	///     let mut sum = *cursor.current(&token).unwrap();
+	///     while cursor.advance(&token) { sum += cursor.current(&token).unwrap(); }
+	///     sum
+	/// });
+	/// assert_eq!(sum, 6);
+	/// ```
+	pub fn with_token<R>(&mut self, f: impl for<'id> FnOnce(ListToken<'id, '_, T, A>) -> R) -> R {
+		f(ListToken {
+			list:    self,
+			cursors: Rc::default(),
+			_brand:  PhantomData,
+		})
+	}
+}
+
+impl<'id, T, A: Allocator> ListToken<'id, '_, T, A> {
+	/// Create a new cursor at the list's cursor. `O(1)`.
+	pub fn cursor(&self) -> TokenCursor<'id, T, A> {
+		TokenCursor::register(&self.cursors, (self.list.current, self.list.index))
+	}
+
+	/// Get a ref to the list itself. `O(1)`.
+	#[inline]
+	pub fn list(&self) -> &IterList<T, A> {
+		self.list
+	}
+
+	/// Run `f` on every live cursor except `me`. `O(k)` in the number of cursors.
+	fn for_others(&self, me: &Rc<Pos<T>>, mut f: impl FnMut(&Pos<T>)) {
+		let mut cursors = self.cursors.borrow_mut();
+		cursors.retain(|c| c.strong_count() > 0);
+		cursors.iter()
+			.filter_map(Weak::upgrade)
+			.filter(|c| !Rc::ptr_eq(c, me))
+			.for_each(|c| f(&c));
+	}
+
+	/// Shift the other cursors after `count` elements were inserted at index `at`.
+	fn inserted(&self, me: &Rc<Pos<T>>, at: usize, count: usize) {
+		let was_empty = self.list.len == count;
+		self.for_others(me, |c| {
+			let (node, index) = c.get();
+			match was_empty {
+				true  => c.set(me.get()),
+				false => c.set((node, if index >= at { index + count } else { index })),
+			}
+		});
+	}
+
+	/// Shift the other cursors after the element at index `at` was removed.
+	fn removed(&self, me: &Rc<Pos<T>>, at: usize) {
+		self.for_others(me, |c| {
+			let (node, index) = c.get();
+			if index > at { c.set((node, index - 1)); }
+		});
+	}
+}
+
+impl<T, A: Allocator> Clone for TokenCursor<'_, T, A> {
+	fn clone(&self) -> Self {
+		Self::register(&self.cursors, self.pos.get())
+	}
+}
+
+impl<'id, T, A: Allocator> TokenCursor<'id, T, A> {
+	fn register(cursors: &Registry<T>, pos: (NonNull<Node<T>>, usize)) -> Self {
+		let pos = Rc::new(Cell::new(pos));
+		cursors.borrow_mut().push(Rc::downgrade(&pos));
+		Self {
+			pos,
+			cursors: cursors.clone(),
+			_brand:  PhantomData,
+			_alloc:  PhantomData,
+		}
+	}
+
+	/// Run a `CursorMut` edit at this cursor's position.
+	fn edit<R>(&mut self, token: &mut ListToken<'id, '_, T, A>, f: impl FnOnce(&mut CursorMut<'_, T, A>) -> R) -> R {
+		let (current, index) = self.pos.get();
+		let mut cursor = CursorMut { current, index, list: &mut *token.list };
+		let res = f(&mut cursor);
+		self.pos.set((cursor.current, cursor.index));
+		res
+	}
+
+	/// Get a ref to the current element. `O(1)`.
+	#[inline]
+	pub fn current<'t>(&self, token: &'t ListToken<'id, '_, T, A>) -> Option<&'t T> {
+		token.list.is_empty().not().then(|| unsafe { &self.pos.get().0.as_ref().elem })
+	}
+
+	/// Get a mut ref to the current element. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	///
+	/// list.with_token(|mut token| {
+	///     let cursor = token.cursor();
+	///     *cursor.current_mut(&mut token).unwrap() = 4;
+	/// });
+	///
+	/// assert_eq!(format!("{:?}", list), "[4, 2, 3]");
+	/// ```
+	#[inline]
+	pub fn current_mut<'t>(&self, token: &'t mut ListToken<'id, '_, T, A>) -> Option<&'t mut T> {
+		token.list.is_empty().not().then(|| unsafe { &mut (*self.pos.get().0.as_ptr()).elem })
+	}
+
+	/// Get the index of the cursor. `O(1)`.
+	#[inline]
+	pub fn index(&self) -> usize {
+		self.pos.get().1
+	}
+
+	/// Check if another live cursor is on the same element. `O(k)` in the number of cursors.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	///
+	/// list.with_token(|token| {
+	///     let mut a = token.cursor();
+	///     let b = a.clone();
+	///     assert!(a.is_shared(&token));
+	///
+	///     a.advance(&token);
+	///     assert!(!b.is_shared(&token));
+	/// });
+	/// ```
+	pub fn is_shared(&self, token: &ListToken<'id, '_, T, A>) -> bool {
+		let mut shared = false;
+		token.for_others(&self.pos, |c| shared |= c.get().0 == self.pos.get().0);
+		shared && !token.list.is_empty()
+	}
+
+	/// Move the cursor one step forward. `O(1)`.
+	/// Returns `false` if the cursor could not be moved.
+	#[inline]
+	#[must_use]
+	pub fn advance(&mut self, token: &ListToken<'id, '_, T, A>) -> bool {
+		if token.list.is_empty() { return false; }
+
+		let (node, index) = self.pos.get();
+		unsafe { node.as_ref() }.next
+			.map(|next| self.pos.set((next, index + 1)))
+			.is_some()
+	}
+
+	/// Move the cursor one step backward. `O(1)`.
+	/// Returns `false` if the cursor could not be moved.
+	#[inline]
+	#[must_use]
+	pub fn retreat(&mut self, token: &ListToken<'id, '_, T, A>) -> bool {
+		if token.list.is_empty() { return false; }
+
+		let (node, index) = self.pos.get();
+		unsafe { node.as_ref() }.prev
+			.map(|prev| self.pos.set((prev, index - 1)))
+			.is_some()
+	}
+
+	/// Move the cursor to the specified index. `O(n)`.
+	/// Walks from whichever of the front, the back or the cursor is closest.
+	/// If the index is out of bounds the cursor will be moved to the edge,
+	/// and `false` will be returned.
+	#[must_use]
+	pub fn move_to(&mut self, token: &ListToken<'id, '_, T, A>, index: usize) -> bool {
+		let (node, at) = self.pos.get();
+		match token.list.node_at(index, Some((at, node))) {
+			Some(node) => { self.pos.set((node, index)); true },
+			None       => { self.move_to_back(token); false },
+		}
+	}
+
+	/// Move the cursor to the front of the list. `O(1)`.
+	/// Returns the number of elements skipped over.
+	pub fn move_to_front(&mut self, token: &ListToken<'id, '_, T, A>) -> usize {
+		let (_, index) = self.pos.replace((token.list.front, 0));
+		index
+	}
+
+	/// Move the cursor to the back of the list. `O(1)`.
+	/// Returns the number of elements skipped over.
+	pub fn move_to_back(&mut self, token: &ListToken<'id, '_, T, A>) -> usize {
+		if token.list.is_empty() { return 0; }
+
+		let back = token.list.len - 1;
+		let (_, index) = self.pos.replace((token.list.back, back));
+		back - index
+	}
+
+	/// Insert an element after the cursor, retaining current position. `O(1)`.
+	/// If the list is empty it will be inserted at index 0.
+	pub fn insert_next(&mut self, token: &mut ListToken<'id, '_, T, A>, elem: T) {
+		let at = self.index() + 1;
+		self.edit(token, |c| c.insert_next(elem));
+		token.inserted(&self.pos, at, 1);
+	}
+
+	/// Insert an element before the cursor, retaining current position. `O(1)`.
+	/// If the list is empty it will be inserted at index 0.
+	pub fn insert_prev(&mut self, token: &mut ListToken<'id, '_, T, A>, elem: T) {
+		let at = self.index();
+		self.edit(token, |c| c.insert_prev(elem));
+		token.inserted(&self.pos, at, 1);
+	}
+
+	/// Push an element after the cursor, moving the cursor to it. `O(1)`.
+	/// If the list is empty it will be inserted at index 0.
+	pub fn push_next(&mut self, token: &mut ListToken<'id, '_, T, A>, elem: T) {
+		let at = self.index() + 1;
+		self.edit(token, |c| c.push_next(elem));
+		token.inserted(&self.pos, at, 1);
+	}
+
+	/// Push an element before the cursor, moving the cursor to it. `O(1)`.
+	/// If the list is empty it will be inserted at index 0.
+	pub fn push_prev(&mut self, token: &mut ListToken<'id, '_, T, A>, elem: T) {
+		let at = self.index();
+		self.edit(token, |c| c.push_prev(elem));
+		token.inserted(&self.pos, at, 1);
+	}
+
+	/// Replace the current element with a new one. `O(1)`.
+	/// Returns the old element.
+	/// If the list is empty, the new element will be inserted, and `None` returned.
+	pub fn replace_cursor(&mut self, token: &mut ListToken<'id, '_, T, A>, elem: T) -> Option<T> {
+		let res = self.edit(token, |c| c.replace_cursor(elem));
+		if res.is_none() { token.inserted(&self.pos, 0, 1); }
+		res
+	}
+}
+
+impl<'id, T> TokenCursor<'id, T> {
+	/// Splice another list in right after the cursor. `O(1)`.
+	/// `cursor` decides where this cursor lands.
+	pub fn splice_after(&mut self, token: &mut ListToken<'id, '_, T>, other: IterList<T>, cursor: Splice) {
+		let (at, count) = (self.index() + 1, other.len);
+		if count == 0 { return; }
+		self.edit(token, |c| c.splice_after(other, cursor));
+		token.inserted(&self.pos, at, count);
+	}
+
+	/// Splice another list in right before the cursor. `O(1)`.
+	/// `cursor` decides where this cursor lands.
+	pub fn splice_before(&mut self, token: &mut ListToken<'id, '_, T>, other: IterList<T>, cursor: Splice) {
+		let (at, count) = (self.index(), other.len);
+		if count == 0 { return; }
+		self.edit(token, |c| c.splice_before(other, cursor));
+		token.inserted(&self.pos, at, count);
+	}
+}
+
+impl<'id, T, A: Allocator> TokenCursor<'id, T, A> {
+	/// Remove the current element and return it. `O(k)` in the number of cursors.
+	/// The cursor will then point to the next element.
+	/// If the removed element was at the end of the list, the cursor will point to the previous
+	/// element and `false` will be returned.
+	///
+	/// Returns `None` if the list is empty, or if another cursor is still on the element.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	///
+	/// list.with_token(|mut token| {
+	///     let mut a = token.cursor();
+	///     let mut b = a.clone();
+	///
+	///     assert_eq!(a.consume_forward(&mut token), None);
+	///
+	///     b.advance(&token);
+	///     assert_eq!(a.consume_forward(&mut token), Some((1, true)));
+	///     assert_eq!(b.index(), 0);
+	/// });
+	///
+	/// assert_eq!(format!("{:?}", list), "[2, 3]");
+	/// ```
+	pub fn consume_forward(&mut self, token: &mut ListToken<'id, '_, T, A>) -> Option<(T, bool)> {
+		if self.is_shared(token) { return None; }
+
+		let at  = self.index();
+		let res = self.edit(token, |c| c.consume_forward());
+		token.removed(&self.pos, at);
+		res
+	}
+
+	/// Remove the current element and return it. `O(k)` in the number of cursors.
+	/// The cursor will then point to the previous element.
+	/// If the removed element was at the front of the list, the cursor will point to the next
+	/// element and `false` will be returned.
+	///
+	/// Returns `None` if the list is empty, or if another cursor is still on the element.
+	pub fn consume_backward(&mut self, token: &mut ListToken<'id, '_, T, A>) -> Option<(T, bool)> {
+		if self.is_shared(token) { return None; }
+
+		let at  = self.index();
+		let res = self.edit(token, |c| c.consume_backward());
+		token.removed(&self.pos, at);
+		res
+	}
+}
@@ -5,9 +5,9 @@ use std::mem;
 use super::node::Node;
 
 pub struct Cursor<T: ?Sized> {
-	current: Option<NonNull<Node<T>>>,
-	index:   usize,
-	len:     usize,
+	pub(super) current: Option<NonNull<Node<T>>>,
+	pub(super) index:   usize,
+	pub(super) len:     usize,
 }
 
 impl<T: ?Sized> Default for Cursor<T> {
@@ -20,17 +20,40 @@ impl<T: ?Sized> Default for Cursor<T> {
 	}
 }
 
-impl<T: Sized> Cursor<T> {
-	fn dangling() -> Self {
-		Self {
-			current: Some(NonNull::dangling()),
-			.. Self::default()
-		}
+impl<T: ?Sized> Cursor<T> {
+	/// Get a ref to the current element. `O(1)`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::new();
+	/// assert_eq!(list.current(), None);
+	///
+	/// list.push_next(1);
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	#[inline]
+	pub fn current(&self) -> Option<&T> {
+		self.current.map(|c| unsafe { &(*c.as_ptr()).elem })
 	}
-}
 
-impl<T: ?Sized> Cursor<T> {
-	/// Move the cursor to the front of the list. `O(n)`.  
+	/// Get the index of the cursor. `O(1)`.
+	#[inline]
+	pub fn index(&self) -> usize {
+		self.index
+	}
+
+	/// Get the number of elements in the list. `O(1)`.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Check if the list is empty. `O(1)`.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Move the cursor to the front of the list. `O(n)`.
 	/// Returns the number of elements traversed.
 	/// ```
 	/// # use iterlist::IterList;
@@ -45,22 +68,11 @@ impl<T: ?Sized> Cursor<T> {
 	/// assert_eq!(offset, 2);
 	/// assert_eq!(list.current(), Some(&1));
 	/// ```
-	pub fn goto_start(&mut self) -> usize {
-		self.current.map_or(0, |ref mut c| {
-			self.index = 0;
-
-			for i in 0_usize.. {
-				match unsafe { c.as_ref().prev } {
-					Some(prev) => *c = prev,
-					None => return i,
-				}
-			}
-
-			unsafe { std::hint::unreachable_unchecked() }
-		})
+	pub fn move_to_front(&mut self) -> usize {
+		(0_usize..).find(|_| !self.retreat()).unwrap_or(0)
 	}
 
-	/// Move the cursor to the back of the list. `O(n)`.  
+	/// Move the cursor to the back of the list. `O(n)`.
 	/// Returns the number of elements traversed.
 	/// ```
 	/// # use iterlist::IterList;
@@ -70,28 +82,17 @@ impl<T: ?Sized> Cursor<T> {
 	/// assert_eq!(offset, 2);
 	/// assert_eq!(list.current(), Some(&3));
 	/// ```
-	pub fn goto_end(&mut self) -> usize {
-		self.current.map_or(0, |ref mut c| {
-			self.index = self.len - 1;
-
-			for i in 0_usize.. {
-				match unsafe { c.as_ref().next } {
-					Some(next) => *c = next,
-					None => return i,
-				}
-			}
-
-			unsafe { std::hint::unreachable_unchecked() }
-		})
+	pub fn move_to_back(&mut self) -> usize {
+		(0_usize..).find(|_| !self.advance()).unwrap_or(0)
 	}
 
 	/// Move the cursor to the specified index. `O(n)`.
-	/// If the index is out of bounds the cursor will be moved to the edge, 
+	/// If the index is out of bounds the cursor will be moved to the edge,
 	/// and `false` will be returned.
 	/// ```
 	/// # use iterlist::IterList;
 	/// let mut list = IterList::from(vec![1, 2, 3]);
-	/// list.move_to(1);
+	/// assert!(list.move_to(1));
 	/// assert_eq!(list.current(), Some(&2));
 	/// ```
 	#[inline]
@@ -104,123 +105,83 @@ impl<T: ?Sized> Cursor<T> {
 		}
 	}
 
-	/// Move the cursor one step forward. `O(1)`.  
+	/// Move the cursor one step forward. `O(1)`.
 	/// Returns `false` if the cursor could not be moved.
 	/// ```
 	/// # use iterlist::IterList;
 	/// let mut list = IterList::from(vec![1, 2, 3]);
 	/// assert_eq!(list.current(), Some(&1));
 	///
-	/// list.advance();
+	/// assert!(list.advance());
 	/// assert_eq!(list.current(), Some(&2));
 	/// ```
 	#[inline]
 	#[must_use]
 	pub fn advance(&mut self) -> bool {
-		self.current.map(|ref mut c| 
-			unsafe { c.as_ref() }.next.map(|n| { *c = n; self.index += 1; }))
-			.is_some()
+		let Some(next) = self.current.and_then(|c| unsafe { c.as_ref() }.next) else { return false };
+		self.current = Some(next);
+		self.index  += 1;
+		true
 	}
 
-	// /// Move the cursor one step forward. `O(1)`.  
-	// ///
-	// /// # Safety
-	// /// This function assumes that the cursor is not at the end of the list,
-	// /// it is up to the caller to ensure this.
-	// #[inline]
-	// pub unsafe fn advance_unchecked(&mut self) {
-	// 	self.current = self.current.as_ref().next.unwrap_unchecked();
-	// 	self.index += 1;
-	// }
-	//
-	// /// Move the cursor one step backward. `O(1)`.  
-	// /// Returns `false` if the cursor could not be moved.
-	// /// ```
-	// /// # use iterlist::IterList;
-	// /// let mut list = IterList::from(vec![1, 2, 3]);
-	// ///
-	// /// list.move_to_back();
-	// /// assert_eq!(list.current(), Some(&3));
-	// ///
-	// /// list.retreat();
-	// /// assert_eq!(list.current(), Some(&2));
-	// /// ```
-	// #[inline]
-	// #[must_use]
-	// pub fn retreat(&mut self) -> bool {
-	// 	if self.len == 0 { return false; }
-	//
-	// 	unsafe { self.current.as_ref() }.prev.map(|prev| {
-	// 			self.current = prev;
-	// 			self.index -= 1; })
-	// 		.is_some()
-	// }
-	//
-	// /// Move the cursor one step backward. `O(1)`.  
-	// ///
-	// /// # Safety
-	// /// This function assumes that the cursor is not at the end of the list,
-	// /// it is up to the caller to ensure this.
-	// #[inline]
-	// pub unsafe fn retreat_unchecked(&mut self) {
-	// 	self.current = self.current.as_ref().prev.unwrap_unchecked();
-	// 	self.index -= 1;
-	// }
-	//
-	// /// Move the cursor by a given offset. `O(n)`.  
-	// /// If the offset is out of bounds the cursor will be moved to the edge, 
-	// /// and `false` will be returned.
-	// /// ```
-	// /// # use iterlist::IterList;
-	// /// let mut list = IterList::from(vec![1, 2, 3]);
-	// ///
-	// /// list.move_to_back();
-	// /// assert_eq!(list.index(), 2);
-	// ///
-	// /// list.move_by(-2);
-	// /// assert_eq!(list.index(), 0);
-	// /// assert_eq!(list.current(), Some(&1));
-	// ///
-	// /// assert!(!list.move_by(10));
-	// /// assert_eq!(list.index(), 2);
-	// /// ```
-	// #[inline]
-	// #[must_use]
-	// pub fn move_by(&mut self, offset: isize) -> bool {
-	// 	match offset.cmp(&0) {
-	// 		Ordering::Greater => (0..offset ).fold(true, |_, _| self.advance()),
-	// 		Ordering::Less    => (0..-offset).fold(true, |_, _| self.retreat()),
-	// 		Ordering::Equal   => true,
-	// 	}
-	// }
+	/// Move the cursor one step backward. `O(1)`.
+	/// Returns `false` if the cursor could not be moved.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	///
+	/// list.move_to_back();
+	/// assert_eq!(list.current(), Some(&3));
+	///
+	/// assert!(list.retreat());
+	/// assert_eq!(list.current(), Some(&2));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn retreat(&mut self) -> bool {
+		let Some(prev) = self.current.and_then(|c| unsafe { c.as_ref() }.prev) else { return false };
+		self.current = Some(prev);
+		self.index  -= 1;
+		true
+	}
+
+	/// Move the cursor by a given offset. `O(n)`.
+	/// If the offset is out of bounds the cursor will be moved to the edge,
+	/// and `false` will be returned.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	///
+	/// list.move_to_back();
+	/// assert_eq!(list.index(), 2);
+	///
+	/// assert!(list.move_by(-2));
+	/// assert_eq!(list.index(), 0);
+	/// assert_eq!(list.current(), Some(&1));
+	///
+	/// assert!(!list.move_by(10));
+	/// assert_eq!(list.index(), 2);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn move_by(&mut self, offset: isize) -> bool {
+		match offset.cmp(&0) {
+			Ordering::Greater => !(0..offset.unsigned_abs()).any(|_| !self.advance()),
+			Ordering::Less    => !(0..offset.unsigned_abs()).any(|_| !self.retreat()),
+			Ordering::Equal   => true,
+		}
+	}
 }
 
 impl<T: ?Sized> Drop for Cursor<T> {
 	/// Drop the list. `O(n)`.
 	fn drop(&mut self) {
-		let Some(mut current) = self.current else { return; };
-
-		if self.index < self.len - self.index {
-			self.goto_start();
-			loop {
-				let next = unsafe { current.as_ref().next };
-				mem::drop(unsafe { Box::from_raw(current.as_ptr()) });
-				match next {
-					Some(next) => current = next,
-					None       => break,
-				}
-			}
-			return;
-		}
+		self.move_to_front();
+		let mut next = self.current.take();
 
-		self.goto_end();
-		loop {
-			let prev = unsafe { current.as_ref().prev };
-			mem::drop(unsafe { Box::from_raw(current.as_ptr()) });
-			match prev {
-				Some(prev) => current = prev,
-				None       => break,
-			}
+		while let Some(node) = next {
+			next = unsafe { node.as_ref() }.next;
+			mem::drop(unsafe { Box::from_raw(node.as_ptr()) });
 		}
 	}
 }
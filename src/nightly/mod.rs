@@ -1,7 +1,5 @@
 use std::marker::PhantomData;
-use std::ptr::{self, NonNull};
 use std::mem;
-use std::cmp::Ordering;
 use std::fmt;
 
 mod node;
@@ -39,9 +37,84 @@ impl<T: ?Sized> IterList<T> {
 	pub fn from_cursor(cursor: Cursor<T>) -> Self {
 		Self { cursor, _boo: PhantomData, }
 	}
+
+	/// Append another list to the back of this one. `O(n)`.  
+	/// Neither list knows where its ends are, so both are walked to find them,
+	/// after that only the boundary nodes are relinked.  
+	/// The cursor is left where it was, if this list was empty it takes over the cursor of `other`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![1, 2, 3]);
+	/// let _ = list.advance();
+	/// list.append(IterList::from(vec![4, 5]));
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3, 4, 5]");
+	/// assert_eq!(list.current(), Some(&2));
+	/// assert_eq!(list.index(), 1);
+	/// assert_eq!(list.len(), 5);
+	///
+	/// let mut empty = IterList::new();
+	/// empty.append(list);
+	/// assert_eq!(empty.current(), Some(&2));
+	/// ```
+	pub fn append(&mut self, mut other: Self) {
+		let Some(mut front) = other.current.take() else { return };
+		let Some(mut back)  = self.current else {
+			other.current = Some(front);
+			return mem::swap(self, &mut other);
+		};
+
+		unsafe {
+			while let Some(next) = back .as_ref().next { back  = next; }
+			while let Some(prev) = front.as_ref().prev { front = prev; }
+
+			back .as_mut().next = Some(front);
+			front.as_mut().prev = Some(back);
+		}
+
+		self.len += mem::take(&mut other.len);
+	}
+
+	/// Prepend another list to the front of this one. `O(n)`.  
+	/// Like `append`, both lists are walked to find their ends before relinking them.  
+	/// The cursor stays on the same element, its index is shifted by the length of `other`.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let mut list = IterList::from(vec![3, 4]);
+	/// list.prepend(IterList::from(vec![1, 2]));
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3, 4]");
+	/// assert_eq!(list.current(), Some(&3));
+	/// assert_eq!(list.index(), 2);
+	///
+	/// list.prepend(IterList::new());
+	/// assert_eq!(list.len(), 4);
+	/// ```
+	pub fn prepend(&mut self, mut other: Self) {
+		let Some(mut back)  = other.current.take() else { return };
+		let Some(mut front) = self.current else {
+			other.current = Some(back);
+			return mem::swap(self, &mut other);
+		};
+
+		unsafe {
+			while let Some(prev) = front.as_ref().prev { front = prev; }
+			while let Some(next) = back .as_ref().next { back  = next; }
+
+			back .as_mut().next = Some(front);
+			front.as_mut().prev = Some(back);
+		}
+
+		self.index += other.len;
+		self.len   += mem::take(&mut other.len);
+	}
 }
 
 impl<T: Sized> IterList<T> {
+	/// Create a new list with N zeroed elements. `O(n)`.
+	///
+	/// # Safety
+	/// Type `T` must be safe to initialize as zeroed.
 	pub unsafe fn new_zeroed(count: usize) -> Self {
 		(0..count).fold(Self::new(), |mut list, _| {
 			list.insert_next(std::mem::MaybeUninit::zeroed().assume_init()); list
@@ -132,10 +205,8 @@ impl<T: Sized> IterList<T> {
 	/// ```
 	#[inline]
 	pub fn push_next(&mut self, elem: T) {
-		self.current.map_or_else(
-			| | self.insert_next(elem),
-			|_| { self.insert_next(elem);
-				let _ = self.advance(); })
+		self.insert_next(elem);
+		let _ = self.advance();
 	}
 
 	/// Push an element before the cursor, moving the cursor to it. `O(1)`.  
@@ -152,11 +223,38 @@ impl<T: Sized> IterList<T> {
 	/// ```
 	#[inline]
 	pub fn push_prev(&mut self, elem: T) {
-		self.current.map_or_else(
-			| | self.insert_prev(elem),
-			|_| { self.insert_prev(elem);
-				let _ = self.retreat(); })
+		self.insert_prev(elem);
+		let _ = self.retreat();
 	}
 }
 
+impl<T: ?Sized + fmt::Debug> fmt::Debug for IterList<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let mut node = self.current;
+		while let Some(prev) = node.and_then(|n| unsafe { n.as_ref() }.prev) { node = Some(prev); }
 
+		let mut list = f.debug_list();
+		while let Some(n) = node {
+			let n = unsafe { &*n.as_ptr() };
+			list.entry(&&n.elem);
+			node = n.next;
+		}
+		list.finish()
+	}
+}
+
+impl<T> From<Vec<T>> for IterList<T> {
+	/// Create a new list from a Vec. `O(n)`.  
+	/// The cursor is set to the first element.
+	/// ```
+	/// # use iterlist::IterList;
+	/// let list = IterList::from(vec![1, 2, 3]);
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	fn from(vec: Vec<T>) -> Self {
+		let mut list = vec.into_iter().fold(Self::new(), |mut list, elem| { list.push_next(elem); list });
+		list.move_to_front();
+		list
+	}
+}
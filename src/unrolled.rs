@@ -0,0 +1,1533 @@
+use std::fmt::Debug;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::mem::{self, MaybeUninit};
+use std::ops::Not;
+use std::ptr::{self, NonNull};
+
+/// An IterList storing up to `N` elements inline in every node, instead of just one.
+/// For small elements like bytes or tokens this saves most of the two links per element,
+/// and walking the list mostly stays within a node, which is a lot kinder to the cache.
+///
+/// The cursor sits on a slot of a node, with the same cursor based api as the boxed `IterList`.
+/// Inserting into a full node splits it in half, and a node that drops under half full
+/// is merged into a neighbour if they fit in one, so any edit touches at most a couple of nodes:
+/// `O(N)` element moves, which is `O(1)` for a fixed `N`. Lookups by index skip whole nodes at a time.
+/// `N` has to be at least 2.
+/// ```
+/// # use iterlist::unrolled::UnrolledIterList;
+/// let mut list: UnrolledIterList<u8, 4> = (1..=6).collect();
+/// list.advance();
+///
+/// assert_eq!(list.consume_forward(), Some((2, true)));
+/// list.insert_prev(7);
+///
+/// assert_eq!(format!("{:?}", list), "[1, 7, 3, 4, 5, 6]");
+/// assert_eq!(list.current(), Some(&3));
+/// assert_eq!(list.index(), 2);
+/// ```
+/// ```compile_fail
+/// # use iterlist::unrolled::UnrolledIterList;
+/// let list: UnrolledIterList<u8, 1> = UnrolledIterList::new();
+/// ```
+pub struct UnrolledIterList<T, const N: usize> {
+	current: NonNull<Chunk<T, N>>,
+	slot:    usize,
+	front:   NonNull<Chunk<T, N>>,
+	back:    NonNull<Chunk<T, N>>,
+	index:   usize,
+	len:     usize,
+	_boo:    PhantomData<T>,
+}
+
+/// A node of the list. Only its first `len` elements are initialized, and it's never empty while linked.
+struct Chunk<T, const N: usize> {
+	next:  Option<NonNull<Chunk<T, N>>>,
+	prev:  Option<NonNull<Chunk<T, N>>>,
+	len:   usize,
+	elems: [MaybeUninit<T>; N],
+}
+
+unsafe impl<T: Send, const N: usize> Send for UnrolledIterList<T, N> {}
+unsafe impl<T: Sync, const N: usize> Sync for UnrolledIterList<T, N> {}
+
+impl<T, const N: usize> Chunk<T, N> {
+	/// Allocate a new unlinked node with no elements in it.
+	fn new_nonnull() -> NonNull<Self> {
+		NonNull::from(Box::leak(Box::new(Self {
+			next:  None,
+			prev:  None,
+			len:   0,
+			elems: [const { MaybeUninit::uninit() }; N],
+		})))
+	}
+
+	#[inline]
+	fn elem(&self, slot: usize) -> &T {
+		debug_assert!(slot < self.len);
+		unsafe { self.elems[slot].assume_init_ref() }
+	}
+
+	#[inline]
+	fn elem_mut(&mut self, slot: usize) -> &mut T {
+		debug_assert!(slot < self.len);
+		unsafe { self.elems[slot].assume_init_mut() }
+	}
+
+	/// Shift the elements from `slot` on up by one, and put `elem` in the gap. `O(N)`.
+	fn insert(&mut self, slot: usize, elem: T) {
+		debug_assert!(self.len < N && slot <= self.len);
+		unsafe {
+			let base = self.elems.as_mut_ptr();
+			ptr::copy(base.add(slot), base.add(slot + 1), self.len - slot);
+		}
+		self.elems[slot].write(elem);
+		self.len += 1;
+	}
+
+	/// Take the element at `slot` out, shifting the ones after it down by one. `O(N)`.
+	fn remove(&mut self, slot: usize) -> T {
+		debug_assert!(slot < self.len);
+		unsafe {
+			let base = self.elems.as_mut_ptr();
+			let elem = base.add(slot).read().assume_init();
+			ptr::copy(base.add(slot + 1), base.add(slot), self.len - slot - 1);
+			self.len -= 1;
+			elem
+		}
+	}
+
+	/// Move the elements of `other` from `from` on over to the end of this node. `O(N)`.
+	fn take_from(&mut self, other: &mut Self, from: usize) {
+		let count = other.len - from;
+		debug_assert!(self.len + count <= N);
+		unsafe {
+			ptr::copy_nonoverlapping(
+				other.elems.as_ptr().add(from), self.elems.as_mut_ptr().add(self.len), count);
+		}
+		self.len += count;
+		other.len = from;
+	}
+}
+
+impl<T, const N: usize> Drop for Chunk<T, N> {
+	fn drop(&mut self) {
+		unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.elems.as_mut_ptr().cast::<T>(), self.len)); }
+	}
+}
+
+impl<T, const N: usize> UnrolledIterList<T, N> {
+	const ROOMY: () = assert!(N >= 2, "an UnrolledIterList needs room for at least 2 elements per node");
+
+	/// Create a new empty list. `O(1)`.
+	/// Does not allocate any memory.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let list: UnrolledIterList<u8, 8> = UnrolledIterList::new();
+	/// assert_eq!(list.len(), 0);
+	/// ```
+	#[inline]
+	pub const fn new() -> Self {
+		#[allow(clippy::let_unit_value)]
+		let () = Self::ROOMY;
+		Self {
+			current: NonNull::dangling(),
+			slot:    0,
+			front:   NonNull::dangling(),
+			back:    NonNull::dangling(),
+			index:   0,
+			len:     0,
+			_boo:    PhantomData,
+		}
+	}
+
+	/// Insert an element after the cursor, retaining current position. `O(1)`.
+	/// If the list is empty it will be inserted at index 0.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list: UnrolledIterList<_, 2> = UnrolledIterList::new();
+	/// list.insert_next(1);
+	/// list.insert_next(2);
+	/// list.insert_next(3);
+	///
+	/// assert_eq!(list.current(), Some(&1));
+	/// assert_eq!(&format!("{:?}", list), "[1, 3, 2]");
+	/// ```
+	pub fn insert_next(&mut self, elem: T) {
+		if self.len == 0 { return self.reset_to(elem); }
+		unsafe { self.insert_at(self.current, self.slot + 1, elem); }
+	}
+
+	/// Insert an element before the cursor, retaining current position. `O(1)`.
+	/// If the list is empty it will be inserted at index 0.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list: UnrolledIterList<_, 2> = UnrolledIterList::new();
+	/// list.insert_prev(1);
+	/// list.insert_prev(2);
+	/// list.insert_prev(3);
+	///
+	/// assert_eq!(list.current(), Some(&1));
+	/// assert_eq!(&format!("{:?}", list), "[2, 3, 1]");
+	/// ```
+	pub fn insert_prev(&mut self, elem: T) {
+		if self.len == 0 { return self.reset_to(elem); }
+		unsafe { self.insert_at(self.current, self.slot, elem); }
+		self.index += 1;
+	}
+
+	/// Push an element after the cursor, moving the cursor to it. `O(1)`.
+	/// If the list is empty it will be inserted at index 0.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list: UnrolledIterList<_, 2> = UnrolledIterList::new();
+	/// list.push_next(1);
+	/// list.push_next(2);
+	/// list.push_next(3);
+	///
+	/// assert_eq!(list.current(), Some(&3));
+	/// assert_eq!(&format!("{:?}", list), "[1, 2, 3]");
+	/// ```
+	pub fn push_next(&mut self, elem: T) {
+		self.insert_next(elem);
+		let _ = self.advance();
+	}
+
+	/// Push an element before the cursor, moving the cursor to it. `O(1)`.
+	/// If the list is empty it will be inserted at index 0.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list: UnrolledIterList<_, 2> = UnrolledIterList::new();
+	/// list.push_prev(1);
+	/// list.push_prev(2);
+	/// list.push_prev(3);
+	///
+	/// assert_eq!(list.current(), Some(&3));
+	/// assert_eq!(&format!("{:?}", list), "[3, 2, 1]");
+	/// ```
+	pub fn push_prev(&mut self, elem: T) {
+		self.insert_prev(elem);
+		let _ = self.retreat();
+	}
+
+	/// Push an element to the front of the list, leaving the cursor on its element. `O(1)`.
+	/// If the list is empty it will be inserted at index 0.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 4>::from(vec![2, 3]);
+	/// list.advance();
+	/// list.push_front(1);
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+	/// assert_eq!(list.current(), Some(&3));
+	/// assert_eq!(list.index(), 2);
+	/// ```
+	pub fn push_front(&mut self, elem: T) {
+		if self.len == 0 { return self.reset_to(elem); }
+		unsafe { self.insert_at(self.front, 0, elem); }
+		self.index += 1;
+	}
+
+	/// Push an element to the back of the list, leaving the cursor on its element. `O(1)`.
+	/// If the list is empty it will be inserted at index 0.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 4>::from(vec![1, 2]);
+	/// list.push_back(3);
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	pub fn push_back(&mut self, elem: T) {
+		if self.len == 0 { return self.reset_to(elem); }
+		unsafe { self.insert_at(self.back, self.back.as_ref().len, elem); }
+	}
+
+	/// Remove the first element and return it. `O(1)`.
+	/// The cursor stays on its element, unless it was the one removed,
+	/// in which case it moves to the new front, just like `consume_forward`.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3]);
+	/// list.move_to_back();
+	///
+	/// assert_eq!(list.pop_front(), Some(1));
+	/// assert_eq!(format!("{:?}", list), "[2, 3]");
+	/// assert_eq!(list.current(), Some(&3));
+	/// assert_eq!(list.index(), 1);
+	/// ```
+	pub fn pop_front(&mut self) -> Option<T> {
+		if self.len == 0 { return None; }
+		if self.index == 0 { return self.consume_forward().map(|(elem, _)| elem); }
+
+		self.index -= 1;
+		Some(unsafe { self.remove_at(self.front, 0) })
+	}
+
+	/// Remove the last element and return it. `O(1)`.
+	/// The cursor stays on its element, unless it was the one removed,
+	/// in which case it moves to the new back, just like `consume_backward`.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3]);
+	///
+	/// assert_eq!(list.pop_back(), Some(3));
+	/// assert_eq!(format!("{:?}", list), "[1, 2]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	pub fn pop_back(&mut self) -> Option<T> {
+		if self.len == 0 { return None; }
+		if self.index == self.len - 1 { return self.consume_backward().map(|(elem, _)| elem); }
+
+		Some(unsafe { self.remove_at(self.back, self.back.as_ref().len - 1) })
+	}
+
+	/// Move the cursor to the front of the list. `O(1)`.
+	/// Returns the number of elements skipped over.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3]);
+	/// list.move_to_back();
+	///
+	/// assert_eq!(list.move_to_front(), 2);
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	pub fn move_to_front(&mut self) -> usize {
+		self.current = self.front;
+		self.slot    = 0;
+		mem::take(&mut self.index)
+	}
+
+	/// Move the cursor to the back of the list. `O(1)`.
+	/// Returns the number of elements skipped over.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3]);
+	///
+	/// assert_eq!(list.move_to_back(), 2);
+	/// assert_eq!(list.current(), Some(&3));
+	/// ```
+	pub fn move_to_back(&mut self) -> usize {
+		if self.len == 0 { return 0; }
+
+		let skipped = self.len - 1 - self.index;
+		self.current = self.back;
+		self.slot    = unsafe { self.back.as_ref().len } - 1;
+		self.index   = self.len - 1;
+		skipped
+	}
+
+	/// Check if the cursor is on the first element. `O(1)`.
+	/// Returns `false` if the list is empty.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3]);
+	/// assert!(list.is_at_front());
+	///
+	/// list.advance();
+	/// assert!(!list.is_at_front());
+	/// ```
+	#[inline]
+	pub fn is_at_front(&self) -> bool {
+		self.len != 0 && self.index == 0
+	}
+
+	/// Check if the cursor is on the last element. `O(1)`.
+	/// Returns `false` if the list is empty.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3]);
+	/// assert!(!list.is_at_back());
+	///
+	/// list.move_to_back();
+	/// assert!(list.is_at_back());
+	/// ```
+	#[inline]
+	pub fn is_at_back(&self) -> bool {
+		self.len != 0 && self.index == self.len - 1
+	}
+
+	/// Move the cursor to the specified index. `O(n / N)`.
+	/// Walks from whichever of the front, the back or the cursor is closest, a node at a time.
+	/// If the index is out of bounds the cursor will be moved to the edge,
+	/// and `false` will be returned.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 2>::from(vec![1, 2, 3, 4, 5]);
+	/// assert!(list.move_to(3));
+	/// assert_eq!(list.current(), Some(&4));
+	///
+	/// assert!(!list.move_to(5));
+	/// assert_eq!(list.current(), Some(&5));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn move_to(&mut self, index: usize) -> bool {
+		match self.locate(index) {
+			Some((node, slot)) => {
+				self.current = node;
+				self.slot    = slot;
+				self.index   = index;
+				true
+			},
+			None => {
+				self.move_to_back();
+				self.is_empty() && index == 0
+			},
+		}
+	}
+
+	/// Move the cursor one step forward. `O(1)`.
+	/// Returns `false` if the cursor could not be moved.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3]);
+	///
+	/// assert!(list.advance());
+	/// assert_eq!(list.current(), Some(&2));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn advance(&mut self) -> bool {
+		if self.len == 0 { return false; }
+
+		let node = unsafe { self.current.as_ref() };
+		if self.slot + 1 < node.len {
+			self.slot += 1;
+		} else {
+			let Some(next) = node.next else { return false; };
+			self.current = next;
+			self.slot    = 0;
+		}
+		self.index += 1;
+		true
+	}
+
+	/// Move the cursor one step backward. `O(1)`.
+	/// Returns `false` if the cursor could not be moved.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3]);
+	/// list.move_to_back();
+	///
+	/// assert!(list.retreat());
+	/// assert_eq!(list.current(), Some(&2));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn retreat(&mut self) -> bool {
+		if self.len == 0 { return false; }
+
+		if self.slot > 0 {
+			self.slot -= 1;
+		} else {
+			let Some(prev) = (unsafe { self.current.as_ref() }).prev else { return false; };
+			self.current = prev;
+			self.slot    = unsafe { prev.as_ref().len } - 1;
+		}
+		self.index -= 1;
+		true
+	}
+
+	/// Move the cursor by a given offset. `O(n / N)`.
+	/// If the offset is out of bounds the cursor will be moved to the edge,
+	/// and `false` will be returned.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3]);
+	///
+	/// assert!(list.move_by(2));
+	/// assert_eq!(list.current(), Some(&3));
+	///
+	/// assert!(!list.move_by(-10));
+	/// assert_eq!(list.index(), 0);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn move_by(&mut self, offset: isize) -> bool {
+		if offset == 0 { return true; }
+
+		match self.index.checked_add_signed(offset) {
+			Some(index) if index < self.len => self.move_to(index),
+			_ if offset < 0 => { self.move_to_front(); false },
+			_ => { self.move_to_back(); false },
+		}
+	}
+
+	/// Get a ref to an element at the given offset from the cursor. `O(n / N)`.
+	/// Returns `None` if the offset is out of bounds.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let list = UnrolledIterList::<_, 2>::from(vec![1, 2, 3]);
+	/// assert_eq!(list.get(2), Some(&3));
+	/// assert_eq!(list.get(-1), None);
+	/// ```
+	#[inline]
+	pub fn get(&self, offset: isize) -> Option<&T> {
+		let (node, slot) = self.index.checked_add_signed(offset).and_then(|index| self.locate(index))?;
+		Some(unsafe { node.as_ref() }.elem(slot))
+	}
+
+	/// Get a mut ref to an element at the given offset from the cursor. `O(n / N)`.
+	/// Returns `None` if the offset is out of bounds.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 2>::from(vec![1, 2, 3]);
+	/// *list.get_mut(2).unwrap() = 4;
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 4]");
+	/// ```
+	#[inline]
+	pub fn get_mut(&mut self, offset: isize) -> Option<&mut T> {
+		let (mut node, slot) = self.index.checked_add_signed(offset).and_then(|index| self.locate(index))?;
+		Some(unsafe { node.as_mut() }.elem_mut(slot))
+	}
+
+	/// Remove the current element and return it. `O(1)`.
+	/// The cursor will then point to the next element.
+	/// If the removed element was at the end of the list, the cursor will point to the previous
+	/// element and `false` will be returned.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3]);
+	/// assert_eq!(list.consume_forward(), Some((1, true)));
+	/// assert_eq!(&format!("{:?}", list), "[2, 3]");
+	/// assert_eq!(list.current(), Some(&2));
+	/// ```
+	pub fn consume_forward(&mut self) -> Option<(T, bool)> {
+		if self.len == 0 { return None; }
+
+		let (node, slot) = (self.current, self.slot);
+		let moved = self.advance();
+		if moved { self.index -= 1; } else { let _ = self.retreat(); }
+
+		Some((unsafe { self.remove_at(node, slot) }, moved))
+	}
+
+	/// Remove the current element and return it. `O(1)`.
+	/// The cursor will then point to the previous element.
+	/// If the removed element was at the front of the list, the cursor will point to the next
+	/// element and `false` will be returned.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3]);
+	/// list.advance();
+	///
+	/// assert_eq!(list.consume_backward(), Some((2, true)));
+	/// assert_eq!(&format!("{:?}", list), "[1, 3]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	pub fn consume_backward(&mut self) -> Option<(T, bool)> {
+		if self.len == 0 { return None; }
+
+		let (node, slot) = (self.current, self.slot);
+		let moved = self.retreat();
+		if !moved && self.advance() { self.index -= 1; }
+
+		Some((unsafe { self.remove_at(node, slot) }, moved))
+	}
+
+	/// Replace the current element with a new one. `O(1)`.
+	/// Returns the old element.
+	/// If the list is empty, the new element will be inserted, and `None` returned.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3]);
+	/// assert_eq!(list.replace_cursor(4), Some(1));
+	/// assert_eq!(format!("{:?}", list), "[4, 2, 3]");
+	/// ```
+	#[inline]
+	pub fn replace_cursor(&mut self, elem: T) -> Option<T> {
+		match self.get_current_mut() {
+			Some(current) => Some(mem::replace(current, elem)),
+			None => { self.reset_to(elem); None },
+		}
+	}
+
+	/// Split the list after the cursor. `O(1)`.
+	/// Only the node the cursor is in has its elements moved, the nodes after it are handed over as they are.
+	/// If the list is empty, or the cursor is at the end, `None` will be returned.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3, 4, 5, 6]);
+	/// list.advance();
+	/// let new_list = list.split_after().unwrap();
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2]");
+	/// assert_eq!(format!("{:?}", new_list), "[3, 4, 5, 6]");
+	/// assert_eq!(new_list.index(), 0);
+	/// ```
+	pub fn split_after(&mut self) -> Option<Self> {
+		if self.is_empty() || self.is_at_back() { return None; }
+
+		unsafe {
+			if self.slot + 1 < self.current.as_ref().len {
+				let tail = Chunk::new_nonnull();
+				self.link_after(tail, self.current);
+				(*tail.as_ptr()).take_from(self.current.as_mut(), self.slot + 1);
+			}
+
+			let mut front = self.current.as_ref().next.unwrap_unchecked();
+			front.as_mut().prev = None;
+			self.current.as_mut().next = None;
+
+			let mut new = Self::new();
+			new.current = front;
+			new.front   = front;
+			new.back    = mem::replace(&mut self.back, self.current);
+			new.len     = self.len - self.index - 1;
+			self.len    = self.index + 1;
+
+			self.settle(self.current);
+			new.settle(front);
+			Some(new)
+		}
+	}
+
+	/// Split the list before the cursor. `O(1)`.
+	/// Only the node the cursor is in has its elements moved, the nodes before it are handed over as they are.
+	/// If the list is empty, or the cursor is at the front, `None` will be returned.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3, 4]);
+	/// list.move_by(2);
+	/// let new_list = list.split_before().unwrap();
+	///
+	/// assert_eq!(format!("{:?}", list), "[3, 4]");
+	/// assert_eq!(format!("{:?}", new_list), "[1, 2]");
+	/// assert_eq!(new_list.index(), 1);
+	/// assert_eq!(list.index(), 0);
+	/// ```
+	pub fn split_before(&mut self) -> Option<Self> {
+		if self.is_empty() || self.is_at_front() { return None; }
+
+		unsafe {
+			if self.slot > 0 {
+				let rest = Chunk::new_nonnull();
+				self.link_after(rest, self.current);
+				(*rest.as_ptr()).take_from(self.current.as_mut(), self.slot);
+				self.current = rest;
+				self.slot    = 0;
+			}
+
+			let mut back = self.current.as_ref().prev.unwrap_unchecked();
+			back.as_mut().next = None;
+			self.current.as_mut().prev = None;
+
+			let mut new = Self::new();
+			new.current = back;
+			new.slot    = back.as_ref().len - 1;
+			new.front   = mem::replace(&mut self.front, self.current);
+			new.back    = back;
+			new.len     = self.index;
+			new.index   = self.index - 1;
+			self.len   -= mem::take(&mut self.index);
+
+			self.settle(self.current);
+			new.settle(back);
+			Some(new)
+		}
+	}
+
+	/// Append another list to the back of this one. `O(1)`.
+	/// The two nodes meeting in the middle are merged if they fit in one.
+	/// The cursor is left where it was, if this list was empty it takes over the cursor of `other`.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3]);
+	/// list.advance();
+	/// list.append(UnrolledIterList::from(vec![4, 5]));
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3, 4, 5]");
+	/// assert_eq!(list.current(), Some(&2));
+	/// assert_eq!(list.index(), 1);
+	/// ```
+	pub fn append(&mut self, mut other: Self) {
+		if other.is_empty() { return; }
+		if self.is_empty() { mem::swap(self, &mut other); return; }
+
+		unsafe {
+			let (back, front) = (self.back, other.front);
+			self.link_chain(back, front);
+			self.back = other.back;
+			self.len += mem::take(&mut other.len);
+			self.join(back, front);
+		}
+	}
+
+	/// Prepend another list to the front of this one. `O(1)`.
+	/// The two nodes meeting in the middle are merged if they fit in one.
+	/// The cursor stays on the same element, its index is shifted by the length of `other`.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 4>::from(vec![3, 4]);
+	/// list.prepend(UnrolledIterList::from(vec![1, 2]));
+	///
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3, 4]");
+	/// assert_eq!(list.current(), Some(&3));
+	/// assert_eq!(list.index(), 2);
+	/// ```
+	pub fn prepend(&mut self, mut other: Self) {
+		if other.is_empty() { return; }
+		if self.is_empty() { mem::swap(self, &mut other); return; }
+
+		unsafe {
+			let (back, front) = (other.back, self.front);
+			self.link_chain(back, front);
+			self.front  = other.front;
+			self.index += other.len;
+			self.len   += mem::take(&mut other.len);
+			self.join(back, front);
+		}
+	}
+
+	/// Get a ref to the current element. `O(1)`.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3]);
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	#[inline]
+	pub fn current(&self) -> Option<&T> {
+		self.is_empty().not().then(|| unsafe { self.current.as_ref() }.elem(self.slot))
+	}
+
+	/// Get a mut ref to the current element. `O(1)`.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3]);
+	/// *list.get_current_mut().unwrap() = 4;
+	/// assert_eq!(format!("{:?}", list), "[4, 2, 3]");
+	/// ```
+	#[inline]
+	pub fn get_current_mut(&mut self) -> Option<&mut T> {
+		self.is_empty().not().then(|| unsafe { self.current.as_mut() }.elem_mut(self.slot))
+	}
+
+	/// Get a ref to the first element. `O(1)`.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3]);
+	/// list.move_to_back();
+	/// assert_eq!(list.front(), Some(&1));
+	/// ```
+	#[inline]
+	pub fn front(&self) -> Option<&T> {
+		self.is_empty().not().then(|| unsafe { self.front.as_ref() }.elem(0))
+	}
+
+	/// Get a ref to the last element. `O(1)`.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3]);
+	/// assert_eq!(list.back(), Some(&3));
+	/// ```
+	#[inline]
+	pub fn back(&self) -> Option<&T> {
+		self.is_empty().not().then(|| {
+			let back = unsafe { self.back.as_ref() };
+			back.elem(back.len - 1)
+		})
+	}
+
+	/// Get the number of elements in the list. `O(1)`.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3]);
+	/// assert_eq!(list.len(), 3);
+	/// ```
+	#[inline]
+	pub const fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Check if the list is empty. `O(1)`.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let list: UnrolledIterList<u8, 4> = UnrolledIterList::new();
+	/// assert!(list.is_empty());
+	/// ```
+	#[inline]
+	pub const fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Get the index of the cursor. `O(1)`.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 2>::from(vec![1, 2, 3]);
+	/// list.move_to_back();
+	/// assert_eq!(list.index(), 2);
+	/// ```
+	#[inline]
+	pub const fn index(&self) -> usize {
+		self.index
+	}
+
+	/// Provides a copy of the current cursor. `O(1)`.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let list = UnrolledIterList::<_, 2>::from(vec![1, 2, 3]);
+	/// let mut cursor = list.as_cursor();
+	///
+	/// assert_eq!(cursor.next(), Some(&1));
+	/// assert_eq!(cursor.next(), Some(&2));
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	#[inline]
+	pub fn as_cursor(&self) -> Cursor<'_, T, N> {
+		Cursor { list: self, current: self.current, slot: self.slot, index: self.index }
+	}
+
+	/// Iterate over the whole list from front to back, wherever the cursor is. `O(1)`.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 2>::from(vec![1, 2, 3]);
+	/// list.move_to_back();
+	///
+	/// assert_eq!(list.iter().collect::<Vec<_>>(), [&1, &2, &3]);
+	/// assert_eq!(list.iter().rev().next(), Some(&3));
+	/// ```
+	#[inline]
+	pub fn iter(&self) -> Iter<'_, T, N> {
+		Iter {
+			front:      self.front,
+			front_slot: 0,
+			back:       self.back,
+			back_slot:  if self.is_empty() { 0 } else { unsafe { self.back.as_ref().len - 1 } },
+			len:        self.len,
+			_boo:       PhantomData,
+		}
+	}
+
+	/// Find the node and slot of the element at `index`. `O(n / N)`.
+	/// Walks from whichever of the front, the back or the cursor is closest.
+	fn locate(&self, index: usize) -> Option<(NonNull<Chunk<T, N>>, usize)> {
+		if index >= self.len { return None; }
+
+		let back = self.len - unsafe { self.back.as_ref().len };
+		let (mut node, mut base) = [(self.front, 0), (self.current, self.index - self.slot), (self.back, back)]
+			.into_iter()
+			.min_by_key(|&(_, base)| base.abs_diff(index))?;
+
+		unsafe {
+			while index < base {
+				node  = node.as_ref().prev.unwrap_unchecked();
+				base -= node.as_ref().len;
+			}
+			while index >= base + node.as_ref().len {
+				base += node.as_ref().len;
+				node  = node.as_ref().next.unwrap_unchecked();
+			}
+		}
+		Some((node, index - base))
+	}
+
+	/// Put `elem` at `slot` of `node`, making room if the node is full. `O(N)`.
+	/// A full node hands the element to a neighbour with room when it goes at either end, and is split in half otherwise.
+	/// The cursor is kept on its element, but it is up to the caller to fix its index.
+	///
+	/// # Safety
+	/// `node` must belong to this list, and `slot` be at most its length.
+	unsafe fn insert_at(&mut self, mut node: NonNull<Chunk<T, N>>, mut slot: usize, elem: T) {
+		if node.as_ref().len == N {
+			let half = N / 2;
+			match slot {
+				0 => match node.as_ref().prev {
+					Some(prev) if prev.as_ref().len < N => { slot = prev.as_ref().len; node = prev; },
+					_ => {
+						let new = Chunk::new_nonnull();
+						self.link_before(new, node);
+						node = new;
+					},
+				},
+				_ if slot == N => match node.as_ref().next {
+					Some(next) if next.as_ref().len < N => { slot = 0; node = next; },
+					_ => {
+						let new = Chunk::new_nonnull();
+						self.link_after(new, node);
+						node = new;
+						slot = 0;
+					},
+				},
+				_ => {
+					let new = Chunk::new_nonnull();
+					self.link_after(new, node);
+					(*new.as_ptr()).take_from(node.as_mut(), half);
+
+					if self.current == node && self.slot >= half {
+						self.current = new;
+						self.slot   -= half;
+					}
+					if slot > half {
+						node  = new;
+						slot -= half;
+					}
+				},
+			}
+		}
+
+		node.as_mut().insert(slot, elem);
+		if self.current == node && self.slot >= slot { self.slot += 1; }
+		self.len += 1;
+	}
+
+	/// Take the element at `slot` of `node` out. `O(N)`.
+	/// The node is freed if that empties it, or merged with a neighbour if it drops under half full.
+	/// The cursor is kept on its element, which must not be the one removed unless it's the last one,
+	/// but it is up to the caller to fix its index.
+	///
+	/// # Safety
+	/// `node` must belong to this list, and `slot` be in bounds.
+	unsafe fn remove_at(&mut self, mut node: NonNull<Chunk<T, N>>, slot: usize) -> T {
+		let elem = node.as_mut().remove(slot);
+		if self.current == node && self.slot > slot { self.slot -= 1; }
+		self.len -= 1;
+
+		match node.as_ref().len {
+			0 => {
+				self.free(node);
+				if self.len == 0 { *self = Self::new(); }
+			},
+			_ => self.settle(node),
+		}
+		elem
+	}
+
+	/// Merge `node` with a neighbour if it's under half full and they fit in one node. `O(N)`.
+	/// The cursor is kept on its element.
+	///
+	/// # Safety
+	/// `node` must belong to this list.
+	unsafe fn settle(&mut self, node: NonNull<Chunk<T, N>>) {
+		if node.as_ref().len * 2 >= N { return; }
+
+		if let Some(next) = node.as_ref().next {
+			if node.as_ref().len + next.as_ref().len <= N { return self.merge(node, next); }
+		}
+		if let Some(prev) = node.as_ref().prev {
+			if prev.as_ref().len + node.as_ref().len <= N { self.merge(prev, node); }
+		}
+	}
+
+	/// Merge two neighbouring nodes that just met if they fit in one. `O(N)`.
+	///
+	/// # Safety
+	/// `node` and `next` must belong to this list, `next` right after `node`.
+	#[inline]
+	unsafe fn join(&mut self, node: NonNull<Chunk<T, N>>, next: NonNull<Chunk<T, N>>) {
+		if node.as_ref().len + next.as_ref().len <= N { self.merge(node, next); }
+	}
+
+	/// Move all the elements of `next` over to the end of `node`, and free `next`. `O(N)`.
+	/// The cursor is kept on its element.
+	///
+	/// # Safety
+	/// `node` and `next` must belong to this list, `next` right after `node`, and their elements must fit in one node.
+	unsafe fn merge(&mut self, mut node: NonNull<Chunk<T, N>>, mut next: NonNull<Chunk<T, N>>) {
+		if self.current == next {
+			self.current = node;
+			self.slot   += node.as_ref().len;
+		}
+		node.as_mut().take_from(next.as_mut(), 0);
+		self.free(next);
+	}
+
+	/// Link an unlinked node in right after `at`. `O(1)`.
+	///
+	/// # Safety
+	/// `at` must belong to this list, and `node` to no list at all.
+	unsafe fn link_after(&mut self, mut node: NonNull<Chunk<T, N>>, mut at: NonNull<Chunk<T, N>>) {
+		node.as_mut().next = at.as_ref().next;
+		node.as_mut().prev = Some(at);
+
+		match at.as_ref().next {
+			Some(mut next) => next.as_mut().prev = Some(node),
+			None => self.back = node,
+		}
+		at.as_mut().next = Some(node);
+	}
+
+	/// Link an unlinked node in right before `at`. `O(1)`.
+	///
+	/// # Safety
+	/// `at` must belong to this list, and `node` to no list at all.
+	unsafe fn link_before(&mut self, mut node: NonNull<Chunk<T, N>>, mut at: NonNull<Chunk<T, N>>) {
+		node.as_mut().prev = at.as_ref().prev;
+		node.as_mut().next = Some(at);
+
+		match at.as_ref().prev {
+			Some(mut prev) => prev.as_mut().next = Some(node),
+			None => self.front = node,
+		}
+		at.as_mut().prev = Some(node);
+	}
+
+	/// Link the end of one chain of nodes to the start of another. `O(1)`.
+	///
+	/// # Safety
+	/// `back` must be the last node of its chain, and `front` the first of its own.
+	#[inline]
+	unsafe fn link_chain(&mut self, mut back: NonNull<Chunk<T, N>>, mut front: NonNull<Chunk<T, N>>) {
+		back.as_mut().next  = Some(front);
+		front.as_mut().prev = Some(back);
+	}
+
+	/// Unlink a node and free it, dropping whatever elements are left in it. `O(N)`.
+	///
+	/// # Safety
+	/// `node` must belong to this list, and the cursor must not be left on it unless the list ends up empty.
+	unsafe fn free(&mut self, node: NonNull<Chunk<T, N>>) {
+		let chunk = Box::from_raw(node.as_ptr());
+
+		match chunk.prev {
+			Some(mut prev) => prev.as_mut().next = chunk.next,
+			None => if let Some(next) = chunk.next { self.front = next; },
+		}
+		match chunk.next {
+			Some(mut next) => next.as_mut().prev = chunk.prev,
+			None => if let Some(prev) = chunk.prev { self.back = prev; },
+		}
+	}
+
+	/// Make `elem` the only element of an empty list.
+	fn reset_to(&mut self, elem: T) {
+		let mut node = Chunk::new_nonnull();
+		unsafe { node.as_mut().insert(0, elem); }
+
+		self.current = node;
+		self.front   = node;
+		self.back    = node;
+		self.slot    = 0;
+		self.index   = 0;
+		self.len     = 1;
+	}
+}
+
+impl<T, const N: usize> Default for UnrolledIterList<T, N> {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T, const N: usize> std::ops::Index<isize> for UnrolledIterList<T, N> {
+	type Output = T;
+
+	/// Essentially equivalent to `get`. `O(n / N)`.
+	/// # Panics
+	/// Panics if the index is out of bounds.
+	#[inline]
+	fn index(&self, index: isize) -> &Self::Output {
+		self.get(index).expect("Index out of bounds")
+	}
+}
+
+impl<T, const N: usize> std::ops::IndexMut<isize> for UnrolledIterList<T, N> {
+	#[inline]
+	fn index_mut(&mut self, index: isize) -> &mut Self::Output {
+		self.get_mut(index).expect("Index out of bounds")
+	}
+}
+
+impl<T: Clone, const N: usize> Clone for UnrolledIterList<T, N> {
+	/// Clone the list. `O(n)`.
+	/// The clone's nodes are packed full, and the cursor position is retained.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 2>::from(vec![1, 2, 3]);
+	/// list.advance();
+	///
+	/// let cloned = list.clone();
+	/// assert_eq!(format!("{:?}", cloned), "[1, 2, 3]");
+	/// assert_eq!(cloned.current(), Some(&2));
+	/// ```
+	fn clone(&self) -> Self {
+		let mut list = self.iter().cloned().collect::<Self>();
+		let _ = list.move_to(self.index);
+		list
+	}
+}
+
+impl<T: Debug, const N: usize> Debug for UnrolledIterList<T, N> {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		f.debug_list().entries(self.iter()).finish()
+	}
+}
+
+impl<T, const N: usize> Drop for UnrolledIterList<T, N> {
+	/// Drop the list. `O(n)`.
+	fn drop(&mut self) {
+		if self.is_empty() { return; }
+
+		let mut node = Some(self.front);
+		while let Some(chunk) = node {
+			let chunk = unsafe { Box::from_raw(chunk.as_ptr()) };
+			node = chunk.next;
+		}
+	}
+}
+
+
+/*
+* ==========================
+* ===== Iteratory bits =====
+* ==========================
+*/
+
+impl<T, const N: usize> From<Vec<T>> for UnrolledIterList<T, N> {
+	/// Create a new list from a Vec. `O(n)`.
+	/// Cursor is set to the front of the list.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3]);
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	#[inline]
+	fn from(vec: Vec<T>) -> Self {
+		vec.into_iter().collect()
+	}
+}
+
+impl<T: Clone, const N: usize> From<&[T]> for UnrolledIterList<T, N> {
+	/// Create a new list from a slice. `O(n)`.
+	/// Cursor is set to the front of the list.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let array: &[u8] = &[1, 2, 3];
+	/// let list = UnrolledIterList::<_, 4>::from(array);
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+	/// ```
+	#[inline]
+	fn from(slice: &[T]) -> Self {
+		slice.iter().cloned().collect()
+	}
+}
+
+impl<T, const N: usize> FromIterator<T> for UnrolledIterList<T, N> {
+	/// Create a new list from an iterator. `O(n)`.
+	/// Nodes are packed full, and the cursor is set to the front of the list.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let list = (1..=3).collect::<UnrolledIterList<_, 4>>();
+	/// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+	/// assert_eq!(list.current(), Some(&1));
+	/// ```
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		let mut list = Self::new();
+		iter.into_iter().for_each(|elem| list.push_back(elem));
+		list
+	}
+}
+
+/// A borrowing iterator over the whole list, front to back. Made by `UnrolledIterList::iter`.
+pub struct Iter<'a, T, const N: usize> {
+	front:      NonNull<Chunk<T, N>>,
+	front_slot: usize,
+	back:       NonNull<Chunk<T, N>>,
+	back_slot:  usize,
+	len:        usize,
+	_boo:       PhantomData<&'a T>,
+}
+
+unsafe impl<T: Sync, const N: usize> Send for Iter<'_, T, N> {}
+unsafe impl<T: Sync, const N: usize> Sync for Iter<'_, T, N> {}
+
+impl<'a, T, const N: usize> Iterator for Iter<'a, T, N> {
+	type Item = &'a T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.len == 0 { return None; }
+		self.len -= 1;
+
+		let node = unsafe { &*self.front.as_ptr() };
+		let elem = node.elem(self.front_slot);
+
+		self.front_slot += 1;
+		if self.front_slot == node.len && self.len != 0 {
+			self.front      = unsafe { node.next.unwrap_unchecked() };
+			self.front_slot = 0;
+		}
+		Some(elem)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>)
+		{ (self.len, Some(self.len)) }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for Iter<'_, T, N> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.len == 0 { return None; }
+		self.len -= 1;
+
+		let node = unsafe { &*self.back.as_ptr() };
+		let elem = node.elem(self.back_slot);
+
+		if self.len != 0 {
+			match self.back_slot {
+				0 => {
+					self.back      = unsafe { node.prev.unwrap_unchecked() };
+					self.back_slot = unsafe { self.back.as_ref().len } - 1;
+				},
+				_ => self.back_slot -= 1,
+			}
+		}
+		Some(elem)
+	}
+}
+
+impl<T, const N: usize> ExactSizeIterator for Iter<'_, T, N> {}
+impl<T, const N: usize> FusedIterator for Iter<'_, T, N> {}
+
+impl<T, const N: usize> Clone for Iter<'_, T, N> {
+	#[inline]
+	fn clone(&self) -> Self {
+		Self { ..*self }
+	}
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a UnrolledIterList<T, N> {
+	type Item     = &'a T;
+	type IntoIter = Iter<'a, T, N>;
+
+	/// Same as `UnrolledIterList::iter`. `O(1)`.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let list = UnrolledIterList::<_, 2>::from(vec![1, 2, 3]);
+	///
+	/// let mut sum = 0;
+	/// for elem in &list { sum += elem; }
+	/// assert_eq!(sum, 6);
+	/// ```
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/// An owning iterator over the list, front to back. Made by `UnrolledIterList::into_iter`.
+/// Elements that aren't yielded are dropped along with it.
+pub struct IntoIter<T, const N: usize> {
+	list: UnrolledIterList<T, N>,
+}
+
+impl<T, const N: usize> IntoIterator for UnrolledIterList<T, N> {
+	type Item     = T;
+	type IntoIter = IntoIter<T, N>;
+
+	/// Turn the list into an iterator over its elements, front to back, wherever the cursor is. `O(1)`.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 2>::from(vec![1, 2, 3, 4]);
+	/// list.move_to_back();
+	///
+	/// let mut iter = list.into_iter();
+	/// assert_eq!(iter.len(), 4);
+	/// assert_eq!(iter.next_back(), Some(4));
+	/// assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3]);
+	/// ```
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		IntoIter { list: self }
+	}
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+	type Item = T;
+
+	/// Internally this call is just `pop_front`. `O(1)`.
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.list.pop_front()
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>)
+		{ (self.list.len, Some(self.list.len)) }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+	/// Internally this call is just `pop_back`. `O(1)`.
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.list.pop_back()
+	}
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+
+
+/*
+* =======================
+* ===== Cursor bits =====
+* =======================
+*/
+
+/// A copy of the cursor of an UnrolledIterList, addressing a node and a slot in it.
+/// It borrows the list, so it can't outlive it or see it change.
+/// ```
+/// # use iterlist::unrolled::UnrolledIterList;
+/// let list = UnrolledIterList::<_, 2>::from(vec![1, 2, 3]);
+/// let mut cursor = list.as_cursor();
+///
+/// assert_eq!(cursor.next(), Some(&1));
+/// assert_eq!(cursor.next(), Some(&2));
+/// assert_eq!(cursor.current(), Some(&3));
+///
+/// assert_eq!(list.current(), Some(&1));
+/// ```
+pub struct Cursor<'i, T, const N: usize> {
+	list:    &'i UnrolledIterList<T, N>,
+	current: NonNull<Chunk<T, N>>,
+	slot:    usize,
+	index:   usize,
+}
+
+unsafe impl<T: Sync, const N: usize> Send for Cursor<'_, T, N> {}
+unsafe impl<T: Sync, const N: usize> Sync for Cursor<'_, T, N> {}
+
+impl<T, const N: usize> Clone for Cursor<'_, T, N> {
+	#[inline]
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<T, const N: usize> Copy for Cursor<'_, T, N> {}
+
+impl<'i, T, const N: usize> Iterator for Cursor<'i, T, N> {
+	type Item = &'i T;
+
+	/// Yield the current element and step past it. `O(1)`.
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let elem = self.current()?;
+		if !self.advance() { self.index = self.list.len; }
+		Some(elem)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let left = self.list.len.saturating_sub(self.index);
+		(left, Some(left))
+	}
+}
+
+impl<'t, T, const N: usize> Cursor<'t, T, N> {
+	/// Get a ref to the current element. `O(1)`.
+	/// Returns `None` once the cursor has iterated past the end.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3]);
+	/// let cursor = list.as_cursor();
+	/// assert_eq!(cursor.current(), Some(&1));
+	/// ```
+	#[inline]
+	pub fn current(&self) -> Option<&'t T> {
+		(self.index < self.list.len).then(|| unsafe { &*self.current.as_ptr() }.elem(self.slot))
+	}
+
+	/// Get the index of the cursor. `O(1)`.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let list = UnrolledIterList::<_, 4>::from(vec![1, 2, 3]);
+	/// let mut cursor = list.as_cursor();
+	/// cursor.next();
+	/// assert_eq!(cursor.index(), 1);
+	/// ```
+	#[inline]
+	pub fn index(&self) -> usize {
+		self.index
+	}
+
+	/// Move the cursor to the front of the list. `O(1)`.
+	/// Returns the number of elements skipped over.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 2>::from(vec![1, 2, 3]);
+	/// list.move_to_back();
+	/// let mut cursor = list.as_cursor();
+	///
+	/// assert_eq!(cursor.move_to_front(), 2);
+	/// assert_eq!(cursor.current(), Some(&1));
+	/// ```
+	#[inline]
+	pub fn move_to_front(&mut self) -> usize {
+		self.current = self.list.front;
+		self.slot    = 0;
+		mem::take(&mut self.index)
+	}
+
+	/// Move the cursor to the back of the list. `O(1)`.
+	/// Returns the number of elements skipped over.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let list = UnrolledIterList::<_, 2>::from(vec![1, 2, 3]);
+	/// let mut cursor = list.as_cursor();
+	///
+	/// assert_eq!(cursor.move_to_back(), 2);
+	/// assert_eq!(cursor.current(), Some(&3));
+	/// ```
+	#[inline]
+	pub fn move_to_back(&mut self) -> usize {
+		if self.list.is_empty() { return 0; }
+
+		let skipped  = (self.list.len - 1).saturating_sub(self.index);
+		self.current = self.list.back;
+		self.slot    = unsafe { self.list.back.as_ref().len } - 1;
+		self.index   = self.list.len - 1;
+		skipped
+	}
+
+	/// Move the cursor one step forward. `O(1)`.
+	/// Returns `false` if the cursor could not be moved.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let list = UnrolledIterList::<_, 2>::from(vec![1, 2, 3]);
+	/// let mut cursor = list.as_cursor();
+	///
+	/// assert!(cursor.advance());
+	/// assert!(cursor.advance());
+	/// assert!(!cursor.advance());
+	/// assert_eq!(cursor.current(), Some(&3));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn advance(&mut self) -> bool {
+		if self.index + 1 >= self.list.len { return false; }
+
+		let node = unsafe { &*self.current.as_ptr() };
+		if self.slot + 1 < node.len {
+			self.slot += 1;
+		} else {
+			self.current = unsafe { node.next.unwrap_unchecked() };
+			self.slot    = 0;
+		}
+		self.index += 1;
+		true
+	}
+
+	/// Move the cursor one step backward. `O(1)`.
+	/// Returns `false` if the cursor could not be moved.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let mut list = UnrolledIterList::<_, 2>::from(vec![1, 2, 3]);
+	/// list.move_to_back();
+	/// let mut cursor = list.as_cursor();
+	///
+	/// assert!(cursor.retreat());
+	/// assert_eq!(cursor.current(), Some(&2));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn retreat(&mut self) -> bool {
+		if self.index == 0 || self.index >= self.list.len { return false; }
+
+		if self.slot > 0 {
+			self.slot -= 1;
+		} else {
+			self.current = unsafe { (*self.current.as_ptr()).prev.unwrap_unchecked() };
+			self.slot    = unsafe { self.current.as_ref().len } - 1;
+		}
+		self.index -= 1;
+		true
+	}
+
+	/// Move the cursor to the specified index. `O(n / N)`.
+	/// If the index is out of bounds the cursor will be moved to the edge,
+	/// and `false` will be returned.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let list = UnrolledIterList::<_, 2>::from(vec![1, 2, 3]);
+	/// let mut cursor = list.as_cursor();
+	///
+	/// assert!(cursor.move_to(2));
+	/// assert_eq!(cursor.current(), Some(&3));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn move_to(&mut self, index: usize) -> bool {
+		match self.list.locate(index) {
+			Some((node, slot)) => {
+				self.current = node;
+				self.slot    = slot;
+				self.index   = index;
+				true
+			},
+			None => { self.move_to_back(); false },
+		}
+	}
+
+	/// Move the cursor by a given offset. `O(n / N)`.
+	/// If the offset is out of bounds the cursor will be moved to the edge,
+	/// and `false` will be returned.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let list = UnrolledIterList::<_, 2>::from(vec![1, 2, 3]);
+	/// let mut cursor = list.as_cursor();
+	///
+	/// assert!(cursor.move_by(2));
+	/// assert_eq!(cursor.index(), 2);
+	///
+	/// assert!(!cursor.move_by(-10));
+	/// assert_eq!(cursor.index(), 0);
+	/// ```
+	#[inline]
+	pub fn move_by(&mut self, offset: isize) -> bool {
+		if offset == 0 { return true; }
+
+		match self.index.checked_add_signed(offset) {
+			Some(index) if index < self.list.len => self.move_to(index),
+			_ if offset < 0 => { self.move_to_front(); false },
+			_ => { self.move_to_back(); false },
+		}
+	}
+
+	/// Get a ref to an element at the given offset from the cursor. `O(n / N)`.
+	/// Returns `None` if the offset is out of bounds.
+	/// ```
+	/// # use iterlist::unrolled::UnrolledIterList;
+	/// let list = UnrolledIterList::<_, 2>::from(vec![1, 2, 3]);
+	/// let mut cursor = list.as_cursor();
+	/// cursor.next();
+	///
+	/// assert_eq!(cursor.get(-1), Some(&1));
+	/// assert_eq!(cursor.get(2), None);
+	/// ```
+	#[inline]
+	pub fn get(&self, offset: isize) -> Option<&'t T> {
+		let (node, slot) = self.index.checked_add_signed(offset).and_then(|index| self.list.locate(index))?;
+		Some(unsafe { &*node.as_ptr() }.elem(slot))
+	}
+}
+
+impl<T, const N: usize> std::ops::Deref for Cursor<'_, T, N> {
+	type Target = T;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		self.current().unwrap()
+	}
+}
+
+impl<T, const N: usize> std::ops::Index<isize> for Cursor<'_, T, N> {
+	type Output = T;
+
+	/// Essentially equivalent to `get`. `O(n / N)`.
+	/// # Panics
+	/// Panics if the index is out of bounds.
+	#[inline]
+	fn index(&self, index: isize) -> &Self::Output {
+		self.get(index).expect("Index out of bounds")
+	}
+}
+
+impl<T: Debug, const N: usize> Debug for Cursor<'_, T, N> {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		write!(f, "{}: {:?}", self.index, self.current())
+	}
+}